@@ -10,7 +10,7 @@ pub fn app() -> Html {
     html! {
         <>
         <ybc::Navbar
-            classes={classes!("is-success")}
+            color={ybc::Color::Success}
             padded=true
             navbrand={html!{
                 <ybc::NavbarItem>
@@ -21,17 +21,17 @@ pub fn app() -> Html {
             navend={html!{
                 <>
                 <ybc::NavbarItem>
-                    <ybc::ButtonAnchor classes={classes!("is-black", "is-outlined")} rel={String::from("noopener noreferrer")} target={String::from("_blank")} href="https://github.com/thedodd/trunk">
+                    <ybc::ButtonAnchor color={ybc::Color::Black} classes={classes!("is-outlined")} rel={String::from("noopener noreferrer")} target={String::from("_blank")} href="https://github.com/thedodd/trunk">
                         {"Trunk"}
                     </ybc::ButtonAnchor>
                 </ybc::NavbarItem>
                 <ybc::NavbarItem>
-                    <ybc::ButtonAnchor classes={classes!("is-black", "is-outlined")} rel={String::from("noopener noreferrer")} target={String::from("_blank")} href="https://yew.rs">
+                    <ybc::ButtonAnchor color={ybc::Color::Black} classes={classes!("is-outlined")} rel={String::from("noopener noreferrer")} target={String::from("_blank")} href="https://yew.rs">
                         {"Yew"}
                     </ybc::ButtonAnchor>
                 </ybc::NavbarItem>
                 <ybc::NavbarItem>
-                    <ybc::ButtonAnchor classes={classes!("is-black", "is-outlined")} rel={String::from("noopener noreferrer")} target={String::from("_blank")} href="https://github.com/thedodd/ybc">
+                    <ybc::ButtonAnchor color={ybc::Color::Black} classes={classes!("is-outlined")} rel={String::from("noopener noreferrer")} target={String::from("_blank")} href="https://github.com/thedodd/ybc">
                         {"YBC"}
                     </ybc::ButtonAnchor>
                 </ybc::NavbarItem>
@@ -40,7 +40,7 @@ pub fn app() -> Html {
         />
 
         <ybc::Hero
-            classes={classes!("is-light")}
+            color={ybc::Color::Light}
             size={ybc::HeroSize::FullheightWithNavbar}
             body={html!{
                 <ybc::Container classes={classes!("is-centered")}>
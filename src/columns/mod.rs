@@ -1,3 +1,6 @@
+#![allow(non_local_definitions)]
+
+use derive_more::Display;
 use yew::prelude::*;
 
 #[derive(Clone, Debug, Properties, PartialEq)]
@@ -15,6 +18,19 @@ pub struct ColumnsProps {
     /// Center all child columns within their row.
     #[prop_or_default]
     pub centered: bool,
+    /// Remove the gap between child columns.
+    #[prop_or_default]
+    pub gapless: bool,
+    /// The size of the gap between child columns; this adds the `is-variable` class.
+    ///
+    /// [https://bulma.io/documentation/columns/gap/#variable-gap](https://bulma.io/documentation/columns/gap/#variable-gap)
+    #[prop_or_default]
+    pub gap: Option<ColumnGap>,
+    /// The viewport width from which child columns are activated, by default only from tablet.
+    ///
+    /// [https://bulma.io/documentation/columns/responsiveness/](https://bulma.io/documentation/columns/responsiveness/)
+    #[prop_or_default]
+    pub activation: Option<ColumnsActivation>,
 }
 
 /// The container for a set of responsive columns.
@@ -22,13 +38,7 @@ pub struct ColumnsProps {
 /// [https://bulma.io/documentation/columns/](https://bulma.io/documentation/columns/)
 #[function_component(Columns)]
 pub fn columns(props: &ColumnsProps) -> Html {
-    let class = classes!(
-        "columns",
-        props.classes.clone(),
-        props.vcentered.then_some("is-vcentered"),
-        props.multiline.then_some("is-multiline"),
-        props.centered.then_some("is-centered"),
-    );
+    let class = columns_classes(props);
     html! {
         <div {class}>
             {props.children.clone()}
@@ -36,6 +46,60 @@ pub fn columns(props: &ColumnsProps) -> Html {
     }
 }
 
+fn columns_classes(props: &ColumnsProps) -> Classes {
+    classes!(
+        "columns",
+        props.classes.clone(),
+        props.vcentered.then_some("is-vcentered"),
+        props.multiline.then_some("is-multiline"),
+        props.centered.then_some("is-centered"),
+        props.gapless.then_some("is-gapless"),
+        props.gap.is_some().then_some("is-variable"),
+        props.gap.as_ref().map(|gap| gap.to_string()),
+        props.activation.as_ref().map(|activation| activation.to_string()),
+    )
+}
+
+/// The gap sizes available for a set of columns, each a multiple of `0.25rem`.
+///
+/// [https://bulma.io/documentation/columns/gap/#variable-gap](https://bulma.io/documentation/columns/gap/#variable-gap)
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+#[display(fmt = "is-{}")]
+pub enum ColumnGap {
+    #[display(fmt = "0")]
+    Zero,
+    #[display(fmt = "1")]
+    One,
+    #[display(fmt = "2")]
+    Two,
+    #[display(fmt = "3")]
+    Three,
+    #[display(fmt = "4")]
+    Four,
+    #[display(fmt = "5")]
+    Five,
+    #[display(fmt = "6")]
+    Six,
+    #[display(fmt = "7")]
+    Seven,
+    #[display(fmt = "8")]
+    Eight,
+}
+
+/// The 2 alternative viewport widths from which columns can be activated.
+///
+/// [https://bulma.io/documentation/columns/responsiveness/](https://bulma.io/documentation/columns/responsiveness/)
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+#[display(fmt = "is-{}")]
+pub enum ColumnsActivation {
+    /// Activate columns on mobile as well.
+    #[display(fmt = "mobile")]
+    Mobile,
+    /// Only activate columns from desktop.
+    #[display(fmt = "desktop")]
+    Desktop,
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////////////////////
 
@@ -45,20 +109,350 @@ pub struct ColumnProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// The width of this column.
+    #[prop_or_default]
+    pub size: Option<ColumnSize>,
+    /// The width of this column on mobile.
+    #[prop_or_default]
+    pub size_mobile: Option<ColumnSize>,
+    /// The width of this column on tablet.
+    #[prop_or_default]
+    pub size_tablet: Option<ColumnSize>,
+    /// The width of this column on touch.
+    #[prop_or_default]
+    pub size_touch: Option<ColumnSize>,
+    /// The width of this column on desktop.
+    #[prop_or_default]
+    pub size_desktop: Option<ColumnSize>,
+    /// The width of this column on widescreen.
+    #[prop_or_default]
+    pub size_widescreen: Option<ColumnSize>,
+    /// The width of this column on fullhd.
+    #[prop_or_default]
+    pub size_fullhd: Option<ColumnSize>,
+    /// The offset of this column, as empty space to its left.
+    #[prop_or_default]
+    pub offset: Option<ColumnOffset>,
+    /// The offset of this column on mobile.
+    #[prop_or_default]
+    pub offset_mobile: Option<ColumnOffset>,
+    /// The offset of this column on tablet.
+    #[prop_or_default]
+    pub offset_tablet: Option<ColumnOffset>,
+    /// The offset of this column on touch.
+    #[prop_or_default]
+    pub offset_touch: Option<ColumnOffset>,
+    /// The offset of this column on desktop.
+    #[prop_or_default]
+    pub offset_desktop: Option<ColumnOffset>,
+    /// The offset of this column on widescreen.
+    #[prop_or_default]
+    pub offset_widescreen: Option<ColumnOffset>,
+    /// The offset of this column on fullhd.
+    #[prop_or_default]
+    pub offset_fullhd: Option<ColumnOffset>,
+    /// Make this column only take the space it needs.
+    #[prop_or_default]
+    pub narrow: bool,
+    /// Make this column only take the space it needs on mobile.
+    #[prop_or_default]
+    pub narrow_mobile: bool,
+    /// Make this column only take the space it needs on tablet.
+    #[prop_or_default]
+    pub narrow_tablet: bool,
+    /// Make this column only take the space it needs on touch.
+    #[prop_or_default]
+    pub narrow_touch: bool,
+    /// Make this column only take the space it needs on desktop.
+    #[prop_or_default]
+    pub narrow_desktop: bool,
+    /// Make this column only take the space it needs on widescreen.
+    #[prop_or_default]
+    pub narrow_widescreen: bool,
+    /// Make this column only take the space it needs on fullhd.
+    #[prop_or_default]
+    pub narrow_fullhd: bool,
 }
 
 /// A flexbox-based responsive column.
 ///
 /// [https://bulma.io/documentation/columns/](https://bulma.io/documentation/columns/)
 ///
-/// This component has a very large number of valid class combinations which users may want.
-/// Modelling all of these is particularly for this component, so for now you are encouraged to
-/// add classes to this Component manually via the `classes` prop.
+/// The width, offset & narrowness of a column can each be set for all viewports, and overridden
+/// per breakpoint via the corresponding `_mobile`, `_tablet`, `_touch`, `_desktop`, `_widescreen`
+/// & `_fullhd` props.
 #[function_component(Column)]
 pub fn column(props: &ColumnProps) -> Html {
+    let class = column_classes(props);
     html! {
-        <div class={classes!("column", props.classes.clone())}>
+        <div {class}>
             {props.children.clone()}
         </div>
     }
 }
+
+fn column_classes(props: &ColumnProps) -> Classes {
+    let sizes = [
+        (&props.size, ""),
+        (&props.size_mobile, "-mobile"),
+        (&props.size_tablet, "-tablet"),
+        (&props.size_touch, "-touch"),
+        (&props.size_desktop, "-desktop"),
+        (&props.size_widescreen, "-widescreen"),
+        (&props.size_fullhd, "-fullhd"),
+    ];
+    let offsets = [
+        (&props.offset, ""),
+        (&props.offset_mobile, "-mobile"),
+        (&props.offset_tablet, "-tablet"),
+        (&props.offset_touch, "-touch"),
+        (&props.offset_desktop, "-desktop"),
+        (&props.offset_widescreen, "-widescreen"),
+        (&props.offset_fullhd, "-fullhd"),
+    ];
+    let narrows = [
+        (props.narrow, ""),
+        (props.narrow_mobile, "-mobile"),
+        (props.narrow_tablet, "-tablet"),
+        (props.narrow_touch, "-touch"),
+        (props.narrow_desktop, "-desktop"),
+        (props.narrow_widescreen, "-widescreen"),
+        (props.narrow_fullhd, "-fullhd"),
+    ];
+
+    let mut class = classes!("column", props.classes.clone());
+    for (size, breakpoint) in sizes {
+        if let Some(size) = size {
+            class.push(format!("is-{}{}", size, breakpoint));
+        }
+    }
+    for (offset, breakpoint) in offsets {
+        if let Some(offset) = offset {
+            class.push(format!("is-offset-{}{}", offset, breakpoint));
+        }
+    }
+    for (narrow, breakpoint) in narrows {
+        if narrow {
+            class.push(format!("is-narrow{}", breakpoint));
+        }
+    }
+    class
+}
+
+/// The widths available for a column.
+///
+/// [https://bulma.io/documentation/columns/sizes/](https://bulma.io/documentation/columns/sizes/)
+///
+/// Unlike most other modifiers, this renders without a class prefix, as the final class depends
+/// on the breakpoint it applies to (`is-half`, `is-half-tablet`).
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+pub enum ColumnSize {
+    #[display(fmt = "three-quarters")]
+    ThreeQuarters,
+    #[display(fmt = "two-thirds")]
+    TwoThirds,
+    #[display(fmt = "half")]
+    Half,
+    #[display(fmt = "one-third")]
+    OneThird,
+    #[display(fmt = "one-quarter")]
+    OneQuarter,
+    #[display(fmt = "full")]
+    Full,
+    #[display(fmt = "four-fifths")]
+    FourFifths,
+    #[display(fmt = "three-fifths")]
+    ThreeFifths,
+    #[display(fmt = "two-fifths")]
+    TwoFifths,
+    #[display(fmt = "one-fifth")]
+    OneFifth,
+    #[display(fmt = "1")]
+    One,
+    #[display(fmt = "2")]
+    Two,
+    #[display(fmt = "3")]
+    Three,
+    #[display(fmt = "4")]
+    Four,
+    #[display(fmt = "5")]
+    Five,
+    #[display(fmt = "6")]
+    Six,
+    #[display(fmt = "7")]
+    Seven,
+    #[display(fmt = "8")]
+    Eight,
+    #[display(fmt = "9")]
+    Nine,
+    #[display(fmt = "10")]
+    Ten,
+    #[display(fmt = "11")]
+    Eleven,
+    #[display(fmt = "12")]
+    Twelve,
+}
+
+/// The offsets available for a column, as empty space to its left, which are the widths of a
+/// `ColumnSize` except for `Full`.
+///
+/// [https://bulma.io/documentation/columns/sizes/#offset](https://bulma.io/documentation/columns/sizes/#offset)
+///
+/// Like `ColumnSize`, this renders without a class prefix (`is-offset-half`, `is-offset-half-tablet`).
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+pub enum ColumnOffset {
+    #[display(fmt = "three-quarters")]
+    ThreeQuarters,
+    #[display(fmt = "two-thirds")]
+    TwoThirds,
+    #[display(fmt = "half")]
+    Half,
+    #[display(fmt = "one-third")]
+    OneThird,
+    #[display(fmt = "one-quarter")]
+    OneQuarter,
+    #[display(fmt = "four-fifths")]
+    FourFifths,
+    #[display(fmt = "three-fifths")]
+    ThreeFifths,
+    #[display(fmt = "two-fifths")]
+    TwoFifths,
+    #[display(fmt = "one-fifth")]
+    OneFifth,
+    #[display(fmt = "1")]
+    One,
+    #[display(fmt = "2")]
+    Two,
+    #[display(fmt = "3")]
+    Three,
+    #[display(fmt = "4")]
+    Four,
+    #[display(fmt = "5")]
+    Five,
+    #[display(fmt = "6")]
+    Six,
+    #[display(fmt = "7")]
+    Seven,
+    #[display(fmt = "8")]
+    Eight,
+    #[display(fmt = "9")]
+    Nine,
+    #[display(fmt = "10")]
+    Ten,
+    #[display(fmt = "11")]
+    Eleven,
+    #[display(fmt = "12")]
+    Twelve,
+}
+
+#[cfg(test)]
+mod tests {
+    use yew::props;
+
+    use super::*;
+
+    #[test]
+    fn columns_classes_default() {
+        let props = props!(ColumnsProps {});
+        assert_eq!(columns_classes(&props).to_string(), "columns");
+    }
+
+    #[test]
+    fn columns_classes_modifiers() {
+        let props = props!(ColumnsProps {
+            gapless: true,
+            activation: ColumnsActivation::Mobile,
+        });
+        assert_eq!(columns_classes(&props).to_string(), "columns is-gapless is-mobile");
+
+        let props = props!(ColumnsProps { activation: ColumnsActivation::Desktop });
+        assert_eq!(columns_classes(&props).to_string(), "columns is-desktop");
+    }
+
+    #[test]
+    fn columns_classes_gap() {
+        let props = props!(ColumnsProps { gap: ColumnGap::Zero });
+        assert_eq!(columns_classes(&props).to_string(), "columns is-variable is-0");
+
+        let props = props!(ColumnsProps { gap: ColumnGap::Eight });
+        assert_eq!(columns_classes(&props).to_string(), "columns is-variable is-8");
+    }
+
+    #[test]
+    fn column_classes_default() {
+        let props = props!(ColumnProps {});
+        assert_eq!(column_classes(&props).to_string(), "column");
+    }
+
+    #[test]
+    fn column_classes_size() {
+        let props = props!(ColumnProps { size: ColumnSize::Half });
+        assert_eq!(column_classes(&props).to_string(), "column is-half");
+
+        let props = props!(ColumnProps {
+            size: ColumnSize::Twelve,
+            size_mobile: ColumnSize::Full,
+            size_tablet: ColumnSize::OneThird,
+            size_touch: ColumnSize::TwoFifths,
+            size_desktop: ColumnSize::Three,
+            size_widescreen: ColumnSize::ThreeQuarters,
+            size_fullhd: ColumnSize::One,
+        });
+        assert_eq!(
+            column_classes(&props).to_string(),
+            "column is-12 is-full-mobile is-one-third-tablet is-two-fifths-touch is-3-desktop is-three-quarters-widescreen is-1-fullhd"
+        );
+    }
+
+    #[test]
+    fn column_classes_offset() {
+        let props = props!(ColumnProps { offset: ColumnOffset::OneQuarter });
+        assert_eq!(column_classes(&props).to_string(), "column is-offset-one-quarter");
+
+        let props = props!(ColumnProps {
+            offset_mobile: ColumnOffset::Half,
+            offset_desktop: ColumnOffset::One,
+            offset_fullhd: ColumnOffset::OneFifth,
+        });
+        assert_eq!(
+            column_classes(&props).to_string(),
+            "column is-offset-half-mobile is-offset-1-desktop is-offset-one-fifth-fullhd"
+        );
+    }
+
+    #[test]
+    fn column_offsets_render_like_sizes() {
+        let pairs = [
+            (ColumnOffset::ThreeQuarters, ColumnSize::ThreeQuarters),
+            (ColumnOffset::OneFifth, ColumnSize::OneFifth),
+            (ColumnOffset::One, ColumnSize::One),
+            (ColumnOffset::Twelve, ColumnSize::Twelve),
+        ];
+        for (offset, size) in pairs {
+            assert_eq!(offset.to_string(), size.to_string());
+        }
+    }
+
+    #[test]
+    fn column_classes_narrow() {
+        let props = props!(ColumnProps { narrow: true });
+        assert_eq!(column_classes(&props).to_string(), "column is-narrow");
+
+        let props = props!(ColumnProps { narrow_touch: true, narrow_widescreen: true });
+        assert_eq!(column_classes(&props).to_string(), "column is-narrow-touch is-narrow-widescreen");
+    }
+
+    #[test]
+    fn column_classes_combined() {
+        let props = props!(ColumnProps {
+            classes: classes!("custom"),
+            size_tablet: ColumnSize::Half,
+            offset_desktop: ColumnOffset::One,
+            narrow: true,
+        });
+        assert_eq!(
+            column_classes(&props).to_string(),
+            "column custom is-half-tablet is-offset-1-desktop is-narrow"
+        );
+    }
+}
@@ -1,3 +1,7 @@
+// The enum-level `#[display(fmt = "...")]` attribute of `derive_more` nests its helper impl in a
+// named const, which newer compilers flag; this is allowed in each module using it.
+#![allow(non_local_definitions)]
+
 use derive_more::Display;
 use std::borrow::Cow;
 use yew::html::IntoPropValue;
@@ -33,3 +37,58 @@ impl IntoPropValue<Cow<'static, str>> for Size {
         Cow::from(self.to_string())
     }
 }
+
+/// Common color classes.
+///
+/// [https://bulma.io/documentation/overview/colors/](https://bulma.io/documentation/overview/colors/)
+///
+/// The `*Light` & `*Dark` variants render the color class along with the `is-light` or `is-dark`
+/// modifier, e.g. `Color::PrimaryLight` renders as `is-primary is-light`.
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+#[display(fmt = "is-{}")]
+pub enum Color {
+    #[display(fmt = "primary")]
+    Primary,
+    #[display(fmt = "link")]
+    Link,
+    #[display(fmt = "info")]
+    Info,
+    #[display(fmt = "success")]
+    Success,
+    #[display(fmt = "warning")]
+    Warning,
+    #[display(fmt = "danger")]
+    Danger,
+    #[display(fmt = "white")]
+    White,
+    #[display(fmt = "black")]
+    Black,
+    #[display(fmt = "light")]
+    Light,
+    #[display(fmt = "dark")]
+    Dark,
+    #[display(fmt = "primary is-light")]
+    PrimaryLight,
+    #[display(fmt = "link is-light")]
+    LinkLight,
+    #[display(fmt = "info is-light")]
+    InfoLight,
+    #[display(fmt = "success is-light")]
+    SuccessLight,
+    #[display(fmt = "warning is-light")]
+    WarningLight,
+    #[display(fmt = "danger is-light")]
+    DangerLight,
+    #[display(fmt = "primary is-dark")]
+    PrimaryDark,
+    #[display(fmt = "link is-dark")]
+    LinkDark,
+    #[display(fmt = "info is-dark")]
+    InfoDark,
+    #[display(fmt = "success is-dark")]
+    SuccessDark,
+    #[display(fmt = "warning is-dark")]
+    WarningDark,
+    #[display(fmt = "danger is-dark")]
+    DangerDark,
+}
@@ -1,7 +1,11 @@
+#![allow(non_local_definitions)]
+
+use std::ops::Range;
+
 use derive_more::Display;
 use yew::prelude::*;
 
-use crate::Alignment;
+use crate::{Alignment, Dropdown};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct BreadcrumbProps {
@@ -19,13 +23,51 @@ pub struct BreadcrumbProps {
     /// The separator type to use between breadcrumb segments.
     #[prop_or_default]
     pub separator: Option<BreadcrumbSeparator>,
+    /// The crumbs of this breadcrumb, rendered instead of the children when not empty. The last
+    /// crumb is marked as the current page.
+    #[prop_or_default]
+    pub crumbs: Vec<Crumb>,
+    /// The maximum number of crumbs shown; the middle crumbs of longer trails are collapsed into
+    /// an ellipsis dropdown.
+    #[prop_or_default]
+    pub max_crumbs: Option<usize>,
 }
 
 /// A simple breadcrumb component to improve your navigation experience.
 ///
 /// [https://bulma.io/documentation/components/breadcrumb/](https://bulma.io/documentation/components/breadcrumb/)
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use ybc::{Breadcrumb, Crumb};
+///
+/// #[function_component(Trail)]
+/// fn trail() -> Html {
+///     let crumbs = vec![Crumb::link("Home", "/"), Crumb::link("Docs", "/docs"), Crumb::new("Breadcrumb")];
+///     html! { <Breadcrumb {crumbs} max_crumbs={Some(4)} /> }
+/// }
+/// ```
+///
+/// For integration with Yew Router, use the `BreadcrumbRouter` component.
 #[function_component(Breadcrumb)]
 pub fn breadcrumb(props: &BreadcrumbProps) -> Html {
+    if !props.crumbs.is_empty() {
+        let crumbs = props
+            .crumbs
+            .iter()
+            .map(|crumb| CrumbView {
+                label: crumb.label.clone(),
+                href: crumb.href.clone(),
+                onclick: None,
+            })
+            .collect();
+        return breadcrumb_nav(props, crumbs_html(crumbs, props.max_crumbs));
+    }
+    breadcrumb_nav(props, props.children.clone().into_iter().collect())
+}
+
+/// The `nav` element of a `Breadcrumb`, holding the given list items.
+fn breadcrumb_nav(props: &BreadcrumbProps, items: Html) -> Html {
     let class = classes!(
         "breadcrumb",
         props.classes.clone(),
@@ -36,12 +78,238 @@ pub fn breadcrumb(props: &BreadcrumbProps) -> Html {
     html! {
         <nav {class} aria-label="breadcrumbs">
             <ul>
-                {props.children.clone()}
+                {items}
             </ul>
         </nav>
     }
 }
 
+/// A crumb of a `Breadcrumb`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Crumb {
+    /// The label of the crumb.
+    pub label: String,
+    /// The URL the crumb links to.
+    pub href: Option<String>,
+}
+
+impl Crumb {
+    /// Create a crumb with the given label, which does not link anywhere.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self { label: label.into(), href: None }
+    }
+
+    /// Create a crumb with the given label, linking to the given URL.
+    pub fn link(label: impl Into<String>, href: impl Into<String>) -> Self {
+        Self { href: Some(href.into()), ..Self::new(label) }
+    }
+}
+
+impl<L: Into<String>, H: Into<String>> From<(L, Option<H>)> for Crumb {
+    fn from((label, href): (L, Option<H>)) -> Self {
+        Self { href: href.map(Into::into), ..Self::new(label) }
+    }
+}
+
+/// A crumb as rendered by `Breadcrumb` & `BreadcrumbRouter`.
+struct CrumbView {
+    label: String,
+    href: Option<String>,
+    onclick: Option<Callback<MouseEvent>>,
+}
+
+/// The `li` elements of the given crumbs, collapsing the middle crumbs beyond `max_crumbs` into an
+/// ellipsis dropdown.
+fn crumbs_html(crumbs: Vec<CrumbView>, max_crumbs: Option<usize>) -> Html {
+    let last = crumbs.len().saturating_sub(1);
+    let hidden = max_crumbs.and_then(|max_crumbs| collapsed_crumbs(crumbs.len(), max_crumbs));
+    let mut items = Vec::with_capacity(crumbs.len());
+    let mut collapsed = Vec::new();
+    for (idx, crumb) in crumbs.into_iter().enumerate() {
+        let CrumbView { label, href, onclick } = crumb;
+        if hidden.as_ref().map(|hidden| hidden.contains(&idx)).unwrap_or(false) {
+            collapsed.push(html! { <a class="dropdown-item" {href} {onclick}>{label}</a> });
+            if Some(idx + 1) == hidden.as_ref().map(|hidden| hidden.end) {
+                items.push(html! {
+                    <li>
+                        <Dropdown button_classes={classes!("is-small", "is-white")} button_html={html! {"…"}}>
+                            {for collapsed.drain(..)}
+                        </Dropdown>
+                    </li>
+                });
+            }
+            continue;
+        }
+        let current = idx == last;
+        items.push(html! {
+            <li class={classes!(current.then_some("is-active"))}>
+                <a {href} aria-current={current.then_some("page")} {onclick}>{label}</a>
+            </li>
+        });
+    }
+    items.into_iter().collect()
+}
+
+/// The indices of the crumbs collapsed into an ellipsis, out of `count` crumbs of which at most
+/// `max_crumbs` are shown: the first crumb & the last `max_crumbs - 1` crumbs are always shown.
+fn collapsed_crumbs(count: usize, max_crumbs: usize) -> Option<Range<usize>> {
+    let max_crumbs = max_crumbs.max(2);
+    (count > max_crumbs).then(|| 1..count - (max_crumbs - 1))
+}
+
+#[cfg(feature = "router")]
+mod router {
+    use super::*;
+    use yew_router::hooks::{use_navigator, use_route};
+    use yew_router::Routable;
+
+    use crate::router::{route_href, route_onclick};
+
+    /// A crumb of a `BreadcrumbRouter`.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct RouterCrumb<R> {
+        /// The label of the crumb.
+        pub label: String,
+        /// The route the crumb links to.
+        pub route: Option<R>,
+    }
+
+    impl<R> RouterCrumb<R> {
+        /// Create a crumb with the given label, linking to the given route if any.
+        pub fn new(label: impl Into<String>, route: Option<R>) -> Self {
+            Self { label: label.into(), route }
+        }
+    }
+
+    impl<R, L: Into<String>> From<(L, Option<R>)> for RouterCrumb<R> {
+        fn from((label, route): (L, Option<R>)) -> Self {
+            Self::new(label, route)
+        }
+    }
+
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct BreadcrumbRouterProps<R: Routable + Clone + PartialEq + 'static> {
+        /// The crumbs of this breadcrumb. When not given, the crumbs are built from the current
+        /// route via `parent` & `label`.
+        #[prop_or_default]
+        pub crumbs: Option<Vec<RouterCrumb<R>>>,
+        /// The callback returning the parent of a route, if any.
+        #[prop_or_default]
+        pub parent: Option<Callback<R, Option<R>>>,
+        /// The callback returning the label of a route; defaults to the path of the route.
+        #[prop_or_default]
+        pub label: Option<Callback<R, String>>,
+        /// The maximum number of crumbs shown; the middle crumbs of longer trails are collapsed
+        /// into an ellipsis dropdown.
+        #[prop_or_default]
+        pub max_crumbs: Option<usize>,
+        #[prop_or_default]
+        pub classes: Classes,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<BreadcrumbSize>,
+        /// The alignment of this component.
+        #[prop_or_default]
+        pub alignment: Option<Alignment>,
+        /// The separator type to use between breadcrumb segments.
+        #[prop_or_default]
+        pub separator: Option<BreadcrumbSeparator>,
+    }
+
+    /// A breadcrumb linking to routes via the Yew Router.
+    ///
+    /// [https://bulma.io/documentation/components/breadcrumb/](https://bulma.io/documentation/components/breadcrumb/)
+    ///
+    /// The crumbs are either given via `crumbs`, or built from the current route by walking up
+    /// its ancestors via the `parent` callback, each crumb being labeled via the `label` callback.
+    /// The last crumb is marked as the current page. This component must be rendered within a
+    /// yew-router `Router`.
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_router::prelude::*;
+    /// use ybc::BreadcrumbRouter;
+    ///
+    /// #[derive(Clone, PartialEq, Routable)]
+    /// enum Route {
+    ///     #[at("/")]
+    ///     Home,
+    ///     #[at("/projects/:id")]
+    ///     Project { id: u32 },
+    /// }
+    ///
+    /// #[function_component(Trail)]
+    /// fn trail() -> Html {
+    ///     let parent = Callback::from(|route| match route {
+    ///         Route::Home => None,
+    ///         Route::Project { .. } => Some(Route::Home),
+    ///     });
+    ///     let label = Callback::from(|route| match route {
+    ///         Route::Home => "Home".to_string(),
+    ///         Route::Project { id } => format!("Project {}", id),
+    ///     });
+    ///     html! { <BreadcrumbRouter<Route> parent={Some(parent)} label={Some(label)} /> }
+    /// }
+    /// ```
+    #[function_component(BreadcrumbRouter)]
+    pub fn breadcrumb_router<R: Routable + Clone + PartialEq + 'static>(props: &BreadcrumbRouterProps<R>) -> Html {
+        let navigator = use_navigator().expect("BreadcrumbRouter must be used within a Router");
+        let current = use_route::<R>();
+
+        let crumbs = props.crumbs.clone().unwrap_or_else(|| {
+            let label = |route: &R| match &props.label {
+                Some(label) => label.emit(route.clone()),
+                None => route.to_path(),
+            };
+            route_trail(current, props.parent.as_ref())
+                .into_iter()
+                .map(|route| RouterCrumb::new(label(&route), Some(route)))
+                .collect()
+        });
+        let crumbs = crumbs
+            .into_iter()
+            .map(|crumb| CrumbView {
+                label: crumb.label,
+                href: crumb
+                    .route
+                    .as_ref()
+                    .map(|route| route_href::<R, ()>(&navigator, route, None)),
+                onclick: crumb
+                    .route
+                    .map(|route| route_onclick::<R, ()>(navigator.clone(), route, None)),
+            })
+            .collect();
+
+        html! {
+            <Breadcrumb
+                classes={props.classes.clone()}
+                size={props.size.clone()}
+                alignment={props.alignment.clone()}
+                separator={props.separator.clone()}
+            >
+                {crumbs_html(crumbs, props.max_crumbs)}
+            </Breadcrumb>
+        }
+    }
+
+    /// The given route preceded by its ancestors, from the root down.
+    pub(super) fn route_trail<R: Clone + PartialEq + 'static>(route: Option<R>, parent: Option<&Callback<R, Option<R>>>) -> Vec<R> {
+        let mut trail: Vec<R> = route.into_iter().collect();
+        while let (Some(parent), Some(route)) = (parent, trail.last()) {
+            match parent.emit(route.clone()) {
+                // Stop on cycles in the route hierarchy.
+                Some(next) if !trail.contains(&next) => trail.push(next),
+                _ => break,
+            }
+        }
+        trail.reverse();
+        trail
+    }
+}
+
+#[cfg(feature = "router")]
+pub use router::{BreadcrumbRouter, BreadcrumbRouterProps, RouterCrumb};
+
 /// The 3 sizes available for a breadcrumb.
 ///
 /// https://bulma.io/documentation/components/breadcrumb/#sizes
@@ -71,3 +339,29 @@ pub enum BreadcrumbSeparator {
     #[display(fmt = "succeeds")]
     Succeeds,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collapsed_crumbs_keep_the_first_and_last_crumbs() {
+        assert_eq!(collapsed_crumbs(3, 4), None);
+        assert_eq!(collapsed_crumbs(4, 4), None);
+        assert_eq!(collapsed_crumbs(6, 4), Some(1..3));
+        assert_eq!(collapsed_crumbs(6, 2), Some(1..5));
+        assert_eq!(collapsed_crumbs(6, 0), Some(1..5));
+    }
+
+    #[cfg(feature = "router")]
+    #[test]
+    fn route_trail_walks_up_the_parents() {
+        let parent = Callback::from(|route: u32| (route > 0).then_some(route / 10));
+        assert_eq!(router::route_trail(Some(123), Some(&parent)), vec![0, 1, 12, 123]);
+        assert_eq!(router::route_trail(Some(123), None), vec![123]);
+        assert_eq!(router::route_trail(None, Some(&parent)), Vec::<u32>::new());
+
+        let cycle = Callback::from(|route: u32| Some((route + 1) % 3));
+        assert_eq!(router::route_trail(Some(0), Some(&cycle)), vec![2, 1, 0]);
+    }
+}
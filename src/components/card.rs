@@ -20,6 +20,67 @@ pub fn card(props: &CardProps) -> Html {
     }
 }
 
+#[cfg(feature = "router")]
+mod router {
+    use super::*;
+    use serde::Serialize;
+    use yew_router::components::Link;
+    use yew_router::Routable;
+
+    #[derive(Clone, Properties, PartialEq)]
+    pub struct CardRouterProps<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + 'static = ()> {
+        /// The Switched item representing the route.
+        pub route: R,
+        /// The query parameters appended to the route, serialized as a query string.
+        #[prop_or_default]
+        pub query: Option<Q>,
+        /// Html inside the component.
+        #[prop_or_default]
+        pub children: Children,
+        #[prop_or_default]
+        pub classes: Classes,
+    }
+
+    /// A Yew Router link rendered as a card container, making the whole card clickable.
+    ///
+    /// [https://bulma.io/documentation/components/card/](https://bulma.io/documentation/components/card/)
+    ///
+    /// The content of the card must not contain other interactive elements, such as links or
+    /// buttons.
+    pub struct CardRouter<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static = ()> {
+        _route: std::marker::PhantomData<R>,
+        _query: std::marker::PhantomData<Q>,
+    }
+
+    impl<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static> Component for CardRouter<R, Q> {
+        type Message = ();
+        type Properties = CardRouterProps<R, Q>;
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self {
+                _route: std::marker::PhantomData,
+                _query: std::marker::PhantomData,
+            }
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            let props = ctx.props();
+            let classes = classes!("card", props.classes.clone());
+            html! {
+                <Link<R, Q>
+                    to={props.route.clone()}
+                    query={props.query.clone()}
+                    {classes}
+                    children={props.children.clone()}
+                />
+            }
+        }
+    }
+}
+
+#[cfg(feature = "router")]
+pub use router::{CardRouter, CardRouterProps};
+
 //////////////////////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////////////////////
 
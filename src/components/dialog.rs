@@ -0,0 +1,391 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use yew::prelude::*;
+
+use crate::{Button, Buttons, Color, Input, ModalCard};
+
+/// The content & appearance of a dialog shown via `use_dialog`.
+///
+/// ```rust
+/// use ybc::{Color, DialogOptions};
+///
+/// let options = DialogOptions {
+///     confirm_label: "Delete".into(),
+///     confirm_color: Some(Color::Danger),
+///     ..DialogOptions::new("Are you sure you want to delete this post?")
+/// };
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DialogOptions {
+    /// The title of the dialog.
+    pub title: String,
+    /// The content of the dialog's body, rendered above the input of a prompt dialog.
+    pub body: Html,
+    /// The label of the confirm button; defaults to `"OK"`.
+    pub confirm_label: String,
+    /// The label of the cancel button, which alert dialogs do not have; defaults to `"Cancel"`.
+    pub cancel_label: String,
+    /// The color of the confirm button; defaults to `Color::Primary`.
+    pub confirm_color: Option<Color>,
+    /// The color of the cancel button.
+    pub cancel_color: Option<Color>,
+    /// The initial value of a prompt dialog's input.
+    pub value: String,
+    /// The placeholder of a prompt dialog's input.
+    pub placeholder: String,
+}
+
+impl DialogOptions {
+    /// Create the options of a dialog with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self { title: title.into(), ..Self::default() }
+    }
+}
+
+impl Default for DialogOptions {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            body: Html::default(),
+            confirm_label: "OK".into(),
+            cancel_label: "Cancel".into(),
+            confirm_color: Some(Color::Primary),
+            cancel_color: None,
+            value: String::new(),
+            placeholder: String::new(),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// A future which resolves to the user's response to a dialog.
+///
+/// The future never resolves if the `DialogProvider` is removed while the dialog is shown.
+pub struct DialogFuture<T>(Rc<RefCell<DialogSlot<T>>>);
+
+/// The shared state of a `DialogFuture` & the callback which resolves it.
+struct DialogSlot<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+impl<T: 'static> DialogFuture<T> {
+    /// Create a future along with the callback which resolves it.
+    fn new() -> (Self, Callback<T>) {
+        let slot = Rc::new(RefCell::new(DialogSlot { value: None, waker: None }));
+        let resolve = {
+            let slot = slot.clone();
+            Callback::from(move |value| {
+                let mut slot = slot.borrow_mut();
+                slot.value = Some(value);
+                if let Some(waker) = slot.waker.take() {
+                    waker.wake();
+                }
+            })
+        };
+        (Self(slot), resolve)
+    }
+}
+
+impl<T> Future for DialogFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.0.borrow_mut();
+        match slot.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// The kind of a dialog, along with the callback to invoke with the user's response.
+#[derive(Clone, Debug, PartialEq)]
+enum DialogKind {
+    Alert(Callback<()>),
+    Confirm(Callback<bool>),
+    Prompt(Callback<Option<String>>),
+}
+
+/// A dialog shown by a `DialogProvider`.
+#[derive(Clone, Debug, PartialEq)]
+struct Dialog {
+    id: usize,
+    kind: DialogKind,
+    options: Rc<DialogOptions>,
+}
+
+/// The stack of dialogs shown by a `DialogProvider`, the last of which is on top.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct DialogStack {
+    dialogs: Vec<Dialog>,
+    next_id: usize,
+}
+
+/// Actions on the stack of dialogs.
+enum DialogAction {
+    Push(DialogKind, Rc<DialogOptions>),
+    Remove(usize),
+}
+
+impl Reducible for DialogStack {
+    type Action = DialogAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut stack = (*self).clone();
+        match action {
+            DialogAction::Push(kind, options) => {
+                stack.dialogs.push(Dialog { id: stack.next_id, kind, options });
+                stack.next_id += 1;
+            }
+            DialogAction::Remove(id) => stack.dialogs.retain(|dialog| dialog.id != id),
+        }
+        Rc::new(stack)
+    }
+}
+
+/// The context provided by a `DialogProvider`, used to show alert, confirm & prompt dialogs.
+///
+/// Each dialog may be awaited via the returned `DialogFuture`, e.g. within
+/// `yew::platform::spawn_local`, or answered via a callback by using the `*_with` variant of
+/// each method. Dialogs shown while another dialog is open are stacked on top of it.
+#[derive(Clone, PartialEq)]
+pub struct DialogContext(UseReducerHandle<DialogStack>);
+
+impl DialogContext {
+    /// Show an alert dialog, resolving once it is dismissed.
+    pub fn alert(&self, options: DialogOptions) -> DialogFuture<()> {
+        let (future, resolve) = DialogFuture::new();
+        self.alert_with(options, resolve);
+        future
+    }
+
+    /// Show an alert dialog, invoking the given callback once it is dismissed.
+    pub fn alert_with(&self, options: DialogOptions, callback: Callback<()>) {
+        self.0
+            .dispatch(DialogAction::Push(DialogKind::Alert(callback), Rc::new(options)));
+    }
+
+    /// Show a confirm dialog, resolving to `true` if confirmed & `false` if cancelled or dismissed.
+    pub fn confirm(&self, options: DialogOptions) -> DialogFuture<bool> {
+        let (future, resolve) = DialogFuture::new();
+        self.confirm_with(options, resolve);
+        future
+    }
+
+    /// Show a confirm dialog, invoking the given callback with `true` if confirmed & `false` if
+    /// cancelled or dismissed.
+    pub fn confirm_with(&self, options: DialogOptions, callback: Callback<bool>) {
+        self.0
+            .dispatch(DialogAction::Push(DialogKind::Confirm(callback), Rc::new(options)));
+    }
+
+    /// Show a prompt dialog, resolving to the text entered if confirmed & `None` if cancelled or
+    /// dismissed. Pressing Enter within the input confirms the prompt.
+    pub fn prompt(&self, options: DialogOptions) -> DialogFuture<Option<String>> {
+        let (future, resolve) = DialogFuture::new();
+        self.prompt_with(options, resolve);
+        future
+    }
+
+    /// Show a prompt dialog, invoking the given callback with the text entered if confirmed &
+    /// `None` if cancelled or dismissed.
+    pub fn prompt_with(&self, options: DialogOptions, callback: Callback<Option<String>>) {
+        self.0
+            .dispatch(DialogAction::Push(DialogKind::Prompt(callback), Rc::new(options)));
+    }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct DialogProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// A context provider which renders the alert, confirm & prompt dialogs shown via `use_dialog`.
+///
+/// Dialogs are rendered using the `ModalCard` markup, & are thus accessible & rendered through a
+/// portal like any other `ModalCard`.
+#[function_component(DialogProvider)]
+pub fn dialog_provider(props: &DialogProviderProps) -> Html {
+    let stack = use_reducer(DialogStack::default);
+    let remove = {
+        let stack = stack.clone();
+        Callback::from(move |id| stack.dispatch(DialogAction::Remove(id)))
+    };
+    html! {
+        <ContextProvider<DialogContext> context={DialogContext(stack.clone())}>
+            {props.children.clone()}
+            {for stack.dialogs.iter().map(|dialog| html! {
+                <DialogView key={dialog.id} dialog={dialog.clone()} remove={remove.clone()} />
+            })}
+        </ContextProvider<DialogContext>>
+    }
+}
+
+/// A hook used to show alert, confirm & prompt dialogs.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use ybc::{use_dialog, Button, DialogOptions};
+///
+/// #[function_component(DeleteButton)]
+/// fn delete_button() -> Html {
+///     let dialog = use_dialog();
+///     let onclick = Callback::from(move |_| {
+///         let confirmed = dialog.confirm(DialogOptions::new("Delete this post?"));
+///         yew::platform::spawn_local(async move {
+///             if confirmed.await {
+///                 // .. snip ..
+///             }
+///         });
+///     });
+///     html! { <Button {onclick}>{"Delete"}</Button> }
+/// }
+/// ```
+///
+/// # Panics
+/// This hook must be used within a `DialogProvider`.
+#[hook]
+pub fn use_dialog() -> DialogContext {
+    use_context::<DialogContext>().expect("use_dialog must be used within a DialogProvider")
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+struct DialogViewProps {
+    dialog: Dialog,
+    /// The callback used for removing the dialog from the stack once answered.
+    remove: Callback<usize>,
+}
+
+/// A single dialog of a `DialogProvider`.
+#[function_component(DialogView)]
+fn dialog_view(props: &DialogViewProps) -> Html {
+    let Dialog { id, kind, options } = props.dialog.clone();
+    let value = use_state(|| options.value.clone());
+
+    // Respond to the dialog & remove it, confirming if `confirmed` is true.
+    let respond = {
+        let (kind, value, remove) = (kind.clone(), value.clone(), props.remove.clone());
+        Callback::from(move |confirmed: bool| {
+            match &kind {
+                DialogKind::Alert(callback) => callback.emit(()),
+                DialogKind::Confirm(callback) => callback.emit(confirmed),
+                DialogKind::Prompt(callback) => callback.emit(confirmed.then(|| (*value).clone())),
+            }
+            remove.emit(id);
+        })
+    };
+
+    let input = match kind {
+        DialogKind::Prompt(_) => {
+            let update = {
+                let value = value.clone();
+                Callback::from(move |text| value.set(text))
+            };
+            // Pressing Enter within the input confirms the prompt, as in a form.
+            let onkeydown = {
+                let respond = respond.clone();
+                Callback::from(move |event: KeyboardEvent| {
+                    if event.key() == "Enter" {
+                        event.prevent_default();
+                        respond.emit(true);
+                    }
+                })
+            };
+            // The name is namespaced, so that the input never picks up the errors of an enclosing
+            // `Form`.
+            html! {
+                <div class="field">
+                    <div class="control" {onkeydown}>
+                        <Input
+                            name={format!("ybc-dialog-{}-value", id)}
+                            value={(*value).clone()}
+                            {update}
+                            placeholder={options.placeholder.clone()}
+                        />
+                    </div>
+                </div>
+            }
+        }
+        _ => html! {},
+    };
+    let cancel = match kind {
+        DialogKind::Alert(_) => html! {},
+        _ => html! {
+            <Button color={options.cancel_color.clone()} onclick={respond.reform(|_| false)}>{options.cancel_label.clone()}</Button>
+        },
+    };
+    let body = html! {
+        <>
+        {options.body.clone()}
+        {input}
+        </>
+    };
+    let footer = html! {
+        <Buttons>
+            <Button color={options.confirm_color.clone()} onclick={respond.reform(|_| true)}>{options.confirm_label.clone()}</Button>
+            {cancel}
+        </Buttons>
+    };
+
+    html! {
+        <ModalCard
+            id={format!("ybc-dialog-{}", id)}
+            title={options.title.clone()}
+            {body}
+            {footer}
+            open={Some(true)}
+            on_close={respond.reform(|_| false)}
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::task::Wake;
+
+    use super::*;
+
+    #[test]
+    fn dialog_stack_pushes_and_removes_by_id() {
+        let alert = || DialogAction::Push(DialogKind::Alert(Callback::noop()), Rc::new(DialogOptions::new("Alert")));
+        let stack = Rc::<DialogStack>::default()
+            .reduce(alert())
+            .reduce(alert())
+            .reduce(alert());
+        assert_eq!(stack.dialogs.iter().map(|dialog| dialog.id).collect::<Vec<_>>(), vec![0, 1, 2]);
+
+        let stack = stack.reduce(DialogAction::Remove(1)).reduce(alert());
+        assert_eq!(stack.dialogs.iter().map(|dialog| dialog.id).collect::<Vec<_>>(), vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn dialog_future_resolves_once_answered() {
+        let (mut future, resolve) = DialogFuture::new();
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        assert!(Pin::new(&mut future).poll(&mut cx).is_pending());
+        resolve.emit(true);
+        assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(true));
+    }
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+}
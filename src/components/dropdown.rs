@@ -86,3 +86,67 @@ impl Component for Dropdown {
         }
     }
 }
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "router")]
+mod router {
+    use super::*;
+    use serde::Serialize;
+    use yew_router::components::Link;
+    use yew_router::Routable;
+
+    #[derive(Clone, Properties, PartialEq)]
+    pub struct DropdownItemRouterProps<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + 'static = ()> {
+        /// The Switched item representing the route.
+        pub route: R,
+        /// The query parameters appended to the route, serialized as a query string.
+        #[prop_or_default]
+        pub query: Option<Q>,
+        /// Html inside the component.
+        #[prop_or_default]
+        pub children: Children,
+        #[prop_or_default]
+        pub classes: Classes,
+        /// Make this element the active / highlighted element.
+        #[prop_or_default]
+        pub active: bool,
+    }
+
+    /// A Yew Router link rendered as an item of a `Dropdown`.
+    ///
+    /// [https://bulma.io/documentation/components/dropdown/](https://bulma.io/documentation/components/dropdown/)
+    pub struct DropdownItemRouter<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static = ()> {
+        _route: std::marker::PhantomData<R>,
+        _query: std::marker::PhantomData<Q>,
+    }
+
+    impl<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static> Component for DropdownItemRouter<R, Q> {
+        type Message = ();
+        type Properties = DropdownItemRouterProps<R, Q>;
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self {
+                _route: std::marker::PhantomData,
+                _query: std::marker::PhantomData,
+            }
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            let props = ctx.props();
+            let classes = classes!("dropdown-item", props.classes.clone(), props.active.then_some("is-active"));
+            html! {
+                <Link<R, Q>
+                    to={props.route.clone()}
+                    query={props.query.clone()}
+                    {classes}
+                    children={props.children.clone()}
+                />
+            }
+        }
+    }
+}
+
+#[cfg(feature = "router")]
+pub use router::{DropdownItemRouter, DropdownItemRouterProps};
@@ -1,5 +1,9 @@
+use std::collections::HashSet;
+
 use yew::prelude::*;
 
+use crate::{Icon, Size};
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct MenuProps {
     #[prop_or_default]
@@ -67,3 +71,392 @@ pub fn menu_label(props: &MenuLabelProps) -> Html {
         </p>
     }
 }
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+/// A section of a data-driven menu: a `menu-label` followed by a `menu-list`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuSection<R = ()> {
+    /// The label of the section.
+    pub label: Option<String>,
+    /// The items of the section.
+    pub items: Vec<MenuItem<R>>,
+}
+
+impl<R> MenuSection<R> {
+    /// Create a section with the given label & items.
+    pub fn new(label: impl Into<String>, items: Vec<MenuItem<R>>) -> Self {
+        Self { label: Some(label.into()), items }
+    }
+}
+
+/// An item of a data-driven menu, which is a collapsible group when it has children.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use ybc::{MenuItem, MenuSection};
+///
+/// let onclick = Callback::from(|_| ());
+/// let sections: Vec<MenuSection> = vec![MenuSection::new("Administration", vec![
+///     MenuItem { href: Some("/settings".into()), ..MenuItem::new("Team settings") },
+///     MenuItem { children: vec![MenuItem { onclick: Some(onclick), ..MenuItem::new("Members") }], ..MenuItem::new("Manage your team") },
+/// ])];
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuItem<R = ()> {
+    /// The ID of the item, used for tracking the open state of groups; defaults to its label.
+    pub id: String,
+    /// The label of the item.
+    pub label: String,
+    /// The icon rendered before the label of the item.
+    pub icon: Option<Html>,
+    /// The route the item links to, used by `MenuRouter`.
+    pub route: Option<R>,
+    /// The URL the item links to.
+    pub href: Option<String>,
+    /// The callback invoked when the item is clicked.
+    pub onclick: Option<Callback<()>>,
+    /// Mark the item as active. `MenuRouter` also marks the item of the current route as active.
+    pub active: bool,
+    /// Open the group of this item initially.
+    pub open: bool,
+    /// The nested items of the item, rendered as a collapsible sub-list. Clicking a group toggles
+    /// it, unless the group also has a `route` or `href`, in which case the click navigates &
+    /// opens the group, leaving an already open group open.
+    pub children: Vec<MenuItem<R>>,
+}
+
+impl<R> MenuItem<R> {
+    /// Create an item with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        let label = label.into();
+        Self {
+            id: label.clone(),
+            label,
+            icon: None,
+            route: None,
+            href: None,
+            onclick: None,
+            active: false,
+            open: false,
+            children: Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct MenuTreeProps {
+    /// The sections of this menu.
+    pub sections: Vec<MenuSection>,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// The callback invoked with the ID & the new open state of a group whenever it is opened or
+    /// closed, including when it is opened because one of its items became active.
+    #[prop_or_default]
+    pub on_toggle: Callback<(String, bool)>,
+}
+
+/// A menu rendered from a tree of sections & items.
+///
+/// [https://bulma.io/documentation/components/menu/](https://bulma.io/documentation/components/menu/)
+///
+/// Items with children are rendered as groups, which are toggled by clicking their label, or only
+/// opened if the group itself links somewhere, so that navigating never collapses it. The
+/// groups containing an active item are opened automatically. For integration with Yew Router, use
+/// the `MenuRouter` component.
+#[function_component(MenuTree)]
+pub fn menu_tree(props: &MenuTreeProps) -> Html {
+    let active = active_items(&props.sections, |_| false);
+    let groups = use_menu_groups(&props.sections, &active, props.on_toggle.clone());
+    let link = |item: &MenuItem| (item.href.clone(), None);
+    html! {
+        <Menu classes={props.classes.clone()}>
+            {menu_sections_html(&props.sections, &active, &groups, &link)}
+        </Menu>
+    }
+}
+
+/// The index paths of the active items of a menu, i.e. the flagged items & the item for which
+/// `is_active` returns `true`.
+fn active_items<R>(sections: &[MenuSection<R>], is_active: impl Fn(&[usize]) -> bool) -> HashSet<Vec<usize>> {
+    let mut active = HashSet::new();
+    visit_items(sections, &mut |path, item| {
+        if item.active || is_active(path) {
+            active.insert(path.to_vec());
+        }
+    });
+    active
+}
+
+/// Invoke `visit` with the index path of each item of the given sections, the first index being
+/// the index of the section.
+fn visit_items<R>(sections: &[MenuSection<R>], visit: &mut impl FnMut(&[usize], &MenuItem<R>)) {
+    fn visit_level<R>(items: &[MenuItem<R>], path: &mut Vec<usize>, visit: &mut impl FnMut(&[usize], &MenuItem<R>)) {
+        for (idx, item) in items.iter().enumerate() {
+            path.push(idx);
+            visit(path, item);
+            visit_level(&item.children, path, visit);
+            path.pop();
+        }
+    }
+    for (idx, section) in sections.iter().enumerate() {
+        visit_level(&section.items, &mut vec![idx], visit);
+    }
+}
+
+/// The IDs of the groups containing any of the given items.
+fn active_groups<R>(sections: &[MenuSection<R>], active: &HashSet<Vec<usize>>) -> Vec<String> {
+    let mut groups = Vec::new();
+    visit_items(sections, &mut |path, item| {
+        let contains_active = active
+            .iter()
+            .any(|active| active.len() > path.len() && active.starts_with(path));
+        if contains_active && !groups.contains(&item.id) {
+            groups.push(item.id.clone());
+        }
+    });
+    groups
+}
+
+/// The open groups of a menu, along with the callback used for toggling a group.
+struct MenuGroups {
+    open: HashSet<String>,
+    toggle: Callback<String>,
+}
+
+/// The open state of the groups of a menu, opening the groups containing active items.
+#[hook]
+fn use_menu_groups<R>(sections: &[MenuSection<R>], active: &HashSet<Vec<usize>>, on_toggle: Callback<(String, bool)>) -> MenuGroups
+where
+    R: 'static,
+{
+    let forced = active_groups(sections, active);
+    let open = {
+        let mut initial: HashSet<String> = forced.iter().cloned().collect();
+        visit_items(sections, &mut |_, item| {
+            if item.open {
+                initial.insert(item.id.clone());
+            }
+        });
+        use_state(move || initial)
+    };
+
+    // Open the groups of newly active items.
+    {
+        let (open, on_toggle) = (open.clone(), on_toggle.clone());
+        use_effect_with_deps(
+            move |forced: &Vec<String>| {
+                let mut next = (*open).clone();
+                let opened: Vec<_> = forced.iter().filter(|id| next.insert((*id).clone())).cloned().collect();
+                if !opened.is_empty() {
+                    open.set(next);
+                    for id in opened {
+                        on_toggle.emit((id, true));
+                    }
+                }
+            },
+            forced,
+        );
+    }
+
+    let toggle = {
+        let open = open.clone();
+        Callback::from(move |id: String| {
+            let mut next = (*open).clone();
+            let is_open = !next.remove(&id);
+            if is_open {
+                next.insert(id.clone());
+            }
+            open.set(next);
+            on_toggle.emit((id, is_open));
+        })
+    };
+    MenuGroups { open: (*open).clone(), toggle }
+}
+
+/// The function returning the `href` & the navigation handler of a menu item.
+type MenuLink<'a, R> = dyn Fn(&MenuItem<R>) -> (Option<String>, Option<Callback<MouseEvent>>) + 'a;
+
+/// The labels & lists of the given menu sections, linking each item via `link`, which returns the
+/// `href` & the navigation handler of an item.
+fn menu_sections_html<R>(sections: &[MenuSection<R>], active: &HashSet<Vec<usize>>, groups: &MenuGroups, link: &MenuLink<R>) -> Html {
+    sections
+        .iter()
+        .enumerate()
+        .map(|(idx, section)| {
+            let label = section.label.clone().map(|text| html! {<MenuLabel {text} />});
+            html! {
+                <>
+                {label}
+                <MenuList>{menu_items_html(&section.items, &mut vec![idx], active, groups, link)}</MenuList>
+                </>
+            }
+        })
+        .collect()
+}
+
+/// Whether clicking an item toggles its group. A group which links somewhere is only opened by a
+/// click, so that navigating to it never collapses it.
+fn toggles_on_click(is_group: bool, is_open: bool, navigates: bool) -> bool {
+    is_group && !(navigates && is_open)
+}
+
+/// The `li` elements of the given menu items, whose parent has the given index path.
+fn menu_items_html<R>(items: &[MenuItem<R>], path: &mut Vec<usize>, active: &HashSet<Vec<usize>>, groups: &MenuGroups, link: &MenuLink<R>) -> Html {
+    items
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            path.push(idx);
+            let is_active = active.contains(path.as_slice());
+            let (href, navigate) = link(item);
+            let is_group = !item.children.is_empty();
+            let is_open = is_group && groups.open.contains(&item.id);
+            let toggles = toggles_on_click(is_group, is_open, href.is_some() || navigate.is_some());
+            let onclick = {
+                let (onclick, toggle, id) = (item.onclick.clone(), groups.toggle.clone(), item.id.clone());
+                Callback::from(move |event: MouseEvent| {
+                    if let Some(navigate) = &navigate {
+                        navigate.emit(event);
+                    }
+                    if let Some(onclick) = &onclick {
+                        onclick.emit(());
+                    }
+                    if toggles {
+                        toggle.emit(id.clone());
+                    }
+                })
+            };
+            let icon = item
+                .icon
+                .clone()
+                .map(|icon| html! {<Icon size={Size::Small}>{icon}</Icon>});
+            let children = is_open.then(|| html! {<ul>{menu_items_html(&item.children, path, active, groups, link)}</ul>});
+            path.pop();
+            html! {
+                <li>
+                    <a
+                        class={classes!(is_active.then_some("is-active"))}
+                        {href}
+                        aria-current={is_active.then_some("page")}
+                        aria-expanded={is_group.then(|| is_open.to_string())}
+                        {onclick}
+                    >
+                        {icon}
+                        <span>{item.label.clone()}</span>
+                    </a>
+                    {children}
+                </li>
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "router")]
+mod router {
+    use super::*;
+    use yew_router::hooks::use_navigator;
+    use yew_router::Routable;
+
+    use crate::router::{best_match, route_href, route_onclick, use_current_path, RouteMatch};
+
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct MenuRouterProps<R: Routable + Clone + PartialEq + 'static> {
+        /// The sections of this menu.
+        pub sections: Vec<MenuSection<R>>,
+        #[prop_or_default]
+        pub classes: Classes,
+        /// The callback invoked with the ID & the new open state of a group whenever it is opened
+        /// or closed, including when it is opened because one of its items became active.
+        #[prop_or_default]
+        pub on_toggle: Callback<(String, bool)>,
+        /// How the current location is matched against the route of each item, to mark it as
+        /// active. Only the item with the most specific matching route is marked as active.
+        #[prop_or_default]
+        pub route_match: RouteMatch,
+    }
+
+    /// A menu rendered from a tree of sections & items, linking to routes via the Yew Router.
+    ///
+    /// [https://bulma.io/documentation/components/menu/](https://bulma.io/documentation/components/menu/)
+    ///
+    /// The item of the current route is marked with `is-active` & `aria-current="page"`, & the
+    /// groups containing it are opened automatically. Items without a route are rendered as with
+    /// `MenuTree`. This component must be rendered within a yew-router `Router`.
+    #[function_component(MenuRouter)]
+    pub fn menu_router<R: Routable + Clone + PartialEq + 'static>(props: &MenuRouterProps<R>) -> Html {
+        let navigator = use_navigator().expect("MenuRouter must be used within a Router");
+        let path = use_current_path().unwrap_or_default();
+
+        let mut targets = Vec::new();
+        visit_items(&props.sections, &mut |item_path, item| {
+            if let Some(route) = &item.route {
+                targets.push((item_path.to_vec(), route.to_path()));
+            }
+        });
+        let current = best_match(&path, targets, props.route_match);
+        let active = active_items(&props.sections, |item_path| current.as_deref() == Some(item_path));
+        let groups = use_menu_groups(&props.sections, &active, props.on_toggle.clone());
+
+        let link = |item: &MenuItem<R>| match &item.route {
+            Some(route) => (
+                Some(route_href::<R, ()>(&navigator, route, None)),
+                Some(route_onclick::<R, ()>(navigator.clone(), route.clone(), None)),
+            ),
+            None => (item.href.clone(), None),
+        };
+        html! {
+            <Menu classes={props.classes.clone()}>
+                {menu_sections_html(&props.sections, &active, &groups, &link)}
+            </Menu>
+        }
+    }
+}
+
+#[cfg(feature = "router")]
+pub use router::{MenuRouter, MenuRouterProps};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sections() -> Vec<MenuSection> {
+        let item = |label: &str, children: Vec<MenuItem>| MenuItem { children, ..MenuItem::new(label) };
+        vec![
+            MenuSection::new("General", vec![item("Dashboard", vec![]), item("Customers", vec![])]),
+            MenuSection::new(
+                "Administration",
+                vec![item(
+                    "Team",
+                    vec![item("Members", vec![item("Invites", vec![])]), item("Plugins", vec![])],
+                )],
+            ),
+        ]
+    }
+
+    #[test]
+    fn active_items_include_flagged_items() {
+        let mut sections = sections();
+        sections[0].items[1].active = true;
+        let active = active_items(&sections, |path| path == [1, 0, 1]);
+        assert_eq!(active, HashSet::from([vec![0, 1], vec![1, 0, 1]]));
+    }
+
+    #[test]
+    fn active_groups_contain_active_items() {
+        let sections = sections();
+        assert_eq!(active_groups(&sections, &HashSet::from([vec![1, 0, 0, 0]])), vec!["Team", "Members"]);
+        assert_eq!(active_groups(&sections, &HashSet::from([vec![1, 0]])), Vec::<String>::new());
+        assert_eq!(active_groups(&sections, &HashSet::from([vec![0, 0]])), Vec::<String>::new());
+    }
+
+    #[test]
+    fn linked_groups_are_only_opened_on_click() {
+        assert!(toggles_on_click(true, false, false));
+        assert!(toggles_on_click(true, true, false));
+        assert!(toggles_on_click(true, false, true));
+        assert!(!toggles_on_click(true, true, true));
+        assert!(!toggles_on_click(false, false, true));
+    }
+}
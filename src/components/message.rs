@@ -1,25 +1,57 @@
 use yew::prelude::*;
 
+use crate::elements::notification::use_dismissible;
+use crate::{Color, Delete};
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct MessageProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Control the visibility of this component from the parent component.
+    ///
+    /// When `None`, this component hides itself once dismissed. When `Some`, this component is
+    /// shown exactly when the value is `true`, and dismissals are only reported via `on_close`.
+    #[prop_or_default]
+    pub open: Option<bool>,
+    /// The callback invoked when this component is dismissed via the delete button of its
+    /// `MessageHeader`, after its fade-out transition if any.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// Fade this component out when dismissed.
+    #[prop_or_default]
+    pub fade: bool,
 }
 
 /// Colored message blocks, to emphasize part of your page.
 ///
 /// [https://bulma.io/documentation/components/message/](https://bulma.io/documentation/components/message/)
+///
+/// A message is dismissed via the delete button of a `dismissible` `MessageHeader`.
 #[function_component(Message)]
 pub fn message(props: &MessageProps) -> Html {
+    let dismissible = use_dismissible(props.open, props.on_close.clone(), props.fade);
+    if !dismissible.visible {
+        return html! {};
+    }
+    let class = classes!("message", props.classes.clone(), props.color.as_ref().map(ToString::to_string));
     html! {
-        <article class={classes!("message", props.classes.clone())}>
-            {props.children.clone()}
-        </article>
+        <ContextProvider<MessageCloser> context={MessageCloser(dismissible.close)}>
+            <article {class} style={dismissible.style}>
+                {props.children.clone()}
+            </article>
+        </ContextProvider<MessageCloser>>
     }
 }
 
+/// The callback used by a `MessageHeader` for dismissing its enclosing `Message`.
+#[derive(Clone, PartialEq)]
+struct MessageCloser(Callback<()>);
+
 //////////////////////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////////////////////
 
@@ -29,6 +61,12 @@ pub struct MessageHeaderProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// Render a delete button at the end of this header, which dismisses the enclosing `Message`.
+    #[prop_or_default]
+    pub dismissible: bool,
+    /// The callback invoked when the delete button of this header is clicked.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
 }
 
 /// An optional message header that can hold a title and a delete element.
@@ -36,9 +74,21 @@ pub struct MessageHeaderProps {
 /// [https://bulma.io/documentation/components/message/](https://bulma.io/documentation/components/message/)
 #[function_component(MessageHeader)]
 pub fn message_header(props: &MessageHeaderProps) -> Html {
+    let closer = use_context::<MessageCloser>();
+    let delete = props.dismissible.then(|| {
+        let on_close = props.on_close.clone();
+        let onclick = Callback::from(move |_| {
+            on_close.emit(());
+            if let Some(MessageCloser(close)) = &closer {
+                close.emit(());
+            }
+        });
+        html! { <Delete {onclick} /> }
+    });
     html! {
         <div class={classes!("message-header", props.classes.clone())}>
             {props.children.clone()}
+            {delete}
         </div>
     }
 }
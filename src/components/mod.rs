@@ -1,5 +1,6 @@
 pub mod breadcrumb;
 pub mod card;
+pub mod dialog;
 pub mod dropdown;
 pub mod menu;
 pub mod message;
@@ -8,3 +9,5 @@ pub mod navbar;
 pub mod pagination;
 pub mod panel;
 pub mod tabs;
+pub mod toast;
+pub mod virtual_scroll;
@@ -1,15 +1,19 @@
 use std::collections::HashSet;
+use std::rc::Rc;
 
-use serde::{Deserialize, Serialize};
-
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
 use yew::prelude::*;
 
-use yew_agent::{use_bridge, HandlerId, Public, UseBridgeHandle, Worker, WorkerLink};
+#[cfg(feature = "agent")]
+pub use agent::{ModalCloseMsg, ModalCloser};
 
 /// Modal actions.
+#[deprecated(note = "modals are no longer driven by messages; open & close them via `ModalContext` or `use_modal`")]
 pub enum ModalMsg {
     Open,
     Close,
+    #[cfg(feature = "agent")]
     CloseFromAgent(ModalCloseMsg),
 }
 
@@ -25,57 +29,70 @@ pub struct ModalProps {
     pub trigger: Html,
     #[prop_or_default]
     pub classes: Classes,
+    /// Control the open state of this modal from the parent component.
+    ///
+    /// When `None`, the modal manages its own state, and is opened by clicking the `trigger`.
+    /// When `Some`, the modal is open exactly when the value is `true`, and requests to close it
+    /// are only reported via `on_close`.
+    #[prop_or_default]
+    pub open: Option<bool>,
+    /// The callback invoked when the modal is dismissed, via its background, its close buttons,
+    /// the Escape key or the `ModalCloser` agent. Closing the modal via `use_modal` does not invoke
+    /// this callback.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// Close the modal when the Escape key is pressed; defaults to `true`.
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    /// The element into which the `div.modal` element is rendered; defaults to `document.body`.
+    #[prop_or_default]
+    pub portal_host: Option<Element>,
 }
 
 /// A classic modal overlay, in which you can include any content you want.
 ///
 /// [https://bulma.io/documentation/components/modal/](https://bulma.io/documentation/components/modal/)
 ///
-/// See the docs on the `ModalCloser` agent to be able to close your modal instance from anywhere
-/// in your app for maximum flexibility.
+/// Render the modal within a `ModalProvider` & use the `use_modal` hook to open, close or toggle
+/// it by ID from anywhere in your app. The `ModalCloser` agent, enabled by the `agent` feature,
+/// may also be used to close the modal by ID, though it is not available when the `ssr` feature
+/// is enabled for server-side rendering.
+///
+/// To open & close the modal from your own app logic instead, pass the `open` prop and update it
+/// from the `on_close` callback.
+/// With the `router` feature, the `use_route_modal` hook binds these props to the URL.
+///
+/// The modal is rendered as an ARIA dialog. When opened, focus is moved into the dialog & Tab
+/// navigation is trapped within it, while scrolling of the page behind it is disabled via the
+/// `is-clipped` class on `<html>`. When closed, focus is returned to the previously focused
+/// element, typically the trigger.
+///
+/// The `div.modal` element is rendered through a portal into `document.body`, or into the
+/// `portal_host` element if given, so that it is not clipped by its surrounding elements. The
+/// trigger is rendered in place. Portals are only available in the browser, so the modal is
+/// rendered in place when the `ssr` feature is enabled. With the `hydration` feature, the modal is
+/// also rendered in place until it has mounted, so that it matches the server-rendered markup.
 #[function_component(Modal)]
 pub fn modal(props: &ModalProps) -> Html {
-    let is_active = use_state(|| false);
-
-    let mut class = Classes::from("modal");
-
-    class.push(props.classes.clone());
-
-    let (opencb, closecb) = if *is_active {
-        class.push("is-active");
-
-        let is_active = is_active.clone();
-
-        (Callback::noop(), Callback::from(move |_| is_active.set(false)))
-    } else {
-        let is_active = is_active.clone();
-
-        (Callback::from(move |_| is_active.set(true)), Callback::noop())
+    let state = use_modal_state(props.id.clone(), props.open, props.on_close.clone(), props.close_on_escape);
+    let class = classes!("modal", props.classes.clone(), state.active.then_some("is-active"));
+    let modal = html! {
+        <div id={props.id.clone()} {class} role="dialog" aria-modal="true" tabindex="-1" ref={state.node} onkeydown={state.keydown}>
+            <div class="modal-background" onclick={state.close.clone()}></div>
+            <div class="modal-content">
+                {props.children.clone()}
+            </div>
+            <button class="modal-close is-large" aria-label="close" onclick={state.close}></button>
+        </div>
     };
-
-    {
-        let id = props.id.clone();
-
-        let _bridge: UseBridgeHandle<ModalCloser> = use_bridge(move |response: ModalCloseMsg| {
-            if response.0 == id {
-                is_active.set(false);
-            } else {
-            }
-        });
-    }
+    let modal = render_portal(props.portal_host.clone(), state.portal, modal);
 
     html! {
         <>
-        <div onclick={opencb}>
+        <div onclick={state.open}>
             {props.trigger.clone()}
         </div>
-        <div id={props.id.clone()} {class}>
-            <div class="modal-background" onclick={closecb.clone()}></div>
-            <div class="modal-content">
-                {props.children.clone()}
-            </div>
-            <button class="modal-close is-large" aria-label="close" onclick={closecb}></button>
-        </div>
+        {modal}
         </>
     }
 }
@@ -100,149 +117,769 @@ pub struct ModalCardProps {
     pub trigger: Html,
     #[prop_or_default]
     pub classes: Classes,
+    /// Control the open state of this modal from the parent component.
+    ///
+    /// When `None`, the modal manages its own state, and is opened by clicking the `trigger`.
+    /// When `Some`, the modal is open exactly when the value is `true`, and requests to close it
+    /// are only reported via `on_close`.
+    #[prop_or_default]
+    pub open: Option<bool>,
+    /// The callback invoked when the modal is dismissed, via its background, its close buttons,
+    /// the Escape key or the `ModalCloser` agent. Closing the modal via `use_modal` does not invoke
+    /// this callback.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// Close the modal when the Escape key is pressed; defaults to `true`.
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    /// The element into which the `div.modal` element is rendered; defaults to `document.body`.
+    #[prop_or_default]
+    pub portal_host: Option<Element>,
 }
 
 /// A classic modal with a header, body, and footer section.
 ///
 /// [https://bulma.io/documentation/components/modal/](https://bulma.io/documentation/components/modal/)
 ///
-/// See the docs on the `ModalCloser` agent to be able to close your modal instance from anywhere
-/// in your app for maximum flexibility.
+/// Render the modal within a `ModalProvider` & use the `use_modal` hook to open, close or toggle
+/// it by ID from anywhere in your app. The `ModalCloser` agent, enabled by the `agent` feature,
+/// may also be used to close the modal by ID, though it is not available when the `ssr` feature
+/// is enabled for server-side rendering.
+///
+/// To open & close the modal from your own app logic instead, pass the `open` prop and update it
+/// from the `on_close` callback.
+/// With the `router` feature, the `use_route_modal` hook binds these props to the URL.
+///
+/// The modal is rendered as an ARIA dialog. When opened, focus is moved into the dialog & Tab
+/// navigation is trapped within it, while scrolling of the page behind it is disabled via the
+/// `is-clipped` class on `<html>`. When closed, focus is returned to the previously focused
+/// element, typically the trigger.
+///
+/// The `div.modal` element is rendered through a portal into `document.body`, or into the
+/// `portal_host` element if given, so that it is not clipped by its surrounding elements. The
+/// trigger is rendered in place. Portals are only available in the browser, so the modal is
+/// rendered in place when the `ssr` feature is enabled. With the `hydration` feature, the modal is
+/// also rendered in place until it has mounted, so that it matches the server-rendered markup.
 #[function_component(ModalCard)]
 pub fn modal_card(props: &ModalCardProps) -> Html {
-    let is_active = use_state(|| false);
+    let state = use_modal_state(props.id.clone(), props.open, props.on_close.clone(), props.close_on_escape);
+    let class = classes!("modal", props.classes.clone(), state.active.then_some("is-active"));
+    let title_id = format!("{}-title", props.id);
+    let modal = html! {
+        <div
+            id={props.id.clone()} {class}
+            role="dialog" aria-modal="true" aria-labelledby={title_id.clone()} tabindex="-1"
+            ref={state.node} onkeydown={state.keydown}
+        >
+            <div class="modal-background" onclick={state.close.clone()}></div>
+            <div class="modal-card">
+                <header class="modal-card-head">
+                    <p id={title_id} class="modal-card-title">{props.title.clone()}</p>
+                    <button class="delete" aria-label="close" onclick={state.close.clone()}></button>
+                </header>
+                <section class="modal-card-body">
+                    {props.body.clone()}
+                </section>
+                <footer class="modal-card-foot">
+                    {props.footer.clone()}
+                </footer>
+            </div>
+            <button class="modal-close is-large" aria-label="close" onclick={state.close}></button>
+        </div>
+    };
+    let modal = render_portal(props.portal_host.clone(), state.portal, modal);
+
+    html! {
+    <>
+        <div onclick={state.open}>
+            {props.trigger.clone()}
+        </div>
+        {modal}
+    </>
+    }
+}
 
-    let mut class = Classes::from("modal");
-    class.push(props.classes.clone());
+/// Render the given `div.modal` element into its portal host, `document.body` by default.
+///
+/// The modal is rendered in place unless `portal` is set. Portals are only available in the
+/// browser, so the modal is always rendered in place when the `ssr` feature is enabled.
+fn render_portal(host: Option<Element>, portal: bool, modal: Html) -> Html {
+    #[cfg(not(feature = "ssr"))]
+    if portal {
+        let host = host.or_else(|| web_sys::window()?.document()?.body().map(Into::into));
+        if let Some(host) = host {
+            return yew::create_portal(modal, host);
+        }
+    }
+    #[cfg(feature = "ssr")]
+    let _ = (host, portal);
+    modal
+}
 
-    let (opencb, closecb) = if *is_active {
-        class.push("is-active");
+/// The elements which may receive focus via Tab navigation within a modal.
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), \
+    textarea:not([disabled]), iframe, [contenteditable], [tabindex]:not([tabindex='-1'])";
+
+/// The state & callbacks shared by `Modal` & `ModalCard`.
+struct UseModalState {
+    /// Whether the modal is active.
+    active: bool,
+    /// Whether the modal is rendered through its portal, which is deferred until mounted when
+    /// hydrating.
+    portal: bool,
+    /// The ref of the `div.modal` element.
+    node: NodeRef,
+    /// The callback of the modal trigger.
+    open: Callback<MouseEvent>,
+    /// The callback of the modal's close elements.
+    close: Callback<MouseEvent>,
+    /// The keyboard handler of the `div.modal` element, handling Escape & the Tab focus trap.
+    keydown: Callback<KeyboardEvent>,
+}
+
+/// The open state shared by `Modal` & `ModalCard`.
+///
+/// When `open` is `Some`, the state is controlled by the parent and the close callback only
+/// reports via `on_close`. Otherwise, the state is held by the enclosing `ModalProvider`, if any,
+/// else by the modal itself.
+#[hook]
+fn use_modal_state(id: String, open: Option<bool>, on_close: Callback<()>, close_on_escape: bool) -> UseModalState {
+    let state = use_state(|| false);
+    let ctx = use_context::<ModalContext>();
+    let node = use_node_ref();
+    // When hydrating, the first render must match the server-rendered markup, in which the modal
+    // is rendered in place.
+    #[cfg(feature = "hydration")]
+    let portal = {
+        let mounted = use_state(|| false);
+        {
+            let mounted = mounted.clone();
+            use_effect_with_deps(move |_| mounted.set(true), ());
+        }
+        *mounted
+    };
+    #[cfg(not(feature = "hydration"))]
+    let portal = true;
+    let is_active = open.unwrap_or_else(|| ctx.as_ref().map(|ctx| ctx.is_open(&id)).unwrap_or(*state));
+
+    let set = {
+        let (id, state) = (id.clone(), state.clone());
+        move |value: bool| match &ctx {
+            Some(ctx) if value => ctx.open(id.clone()),
+            Some(ctx) => ctx.close(id.clone()),
+            None => state.set(value),
+        }
+    };
 
-        let is_active = is_active.clone();
+    let close = {
+        let set = set.clone();
+        Callback::from(move |_| {
+            if open.is_none() {
+                set(false);
+            }
+            on_close.emit(());
+        })
+    };
 
-        (Callback::noop(), Callback::from(move |_| is_active.set(false)))
+    let (opencb, closecb) = if is_active {
+        (Callback::noop(), close.reform(|_| ()))
+    } else if open.is_none() {
+        (Callback::from(move |_| set(true)), Callback::noop())
     } else {
-        let is_active = is_active.clone();
+        (Callback::noop(), Callback::noop())
+    };
 
-        (Callback::from(move |_| is_active.set(true)), Callback::noop())
+    let keydown = {
+        let (node, close) = (node.clone(), close.clone());
+        Callback::from(move |event: KeyboardEvent| match event.key().as_str() {
+            "Escape" if is_active && close_on_escape => {
+                event.prevent_default();
+                close.emit(());
+            }
+            "Tab" if is_active => trap_focus(&node, &event),
+            _ => (),
+        })
     };
 
+    // Focus is only moved into the modal once it has been rendered in its final place, i.e. its
+    // portal, as an in-place element is replaced when moved.
     {
-        let id = props.id.clone();
+        let node = node.clone();
+        use_effect_with_deps(
+            move |&is_active| {
+                let previous = is_active.then(|| on_modal_open(&node)).flatten();
+                move || {
+                    if is_active {
+                        on_modal_close(previous);
+                    }
+                }
+            },
+            is_active && portal,
+        );
+    }
 
-        let _bridge: UseBridgeHandle<ModalCloser> = use_bridge(move |response: ModalCloseMsg| {
-            if response.0 == id {
-                is_active.set(false);
-            } else {
+    // Web workers are only available in the browser, so the closer agent is not used when
+    // rendering on the server.
+    #[cfg(all(feature = "agent", not(feature = "ssr")))]
+    {
+        let _bridge: yew_agent::UseBridgeHandle<ModalCloser> = yew_agent::use_bridge(move |response: ModalCloseMsg| {
+            if is_active && response.0 == id {
+                close.emit(());
             }
         });
     }
+    #[cfg(not(all(feature = "agent", not(feature = "ssr"))))]
+    let _ = (id, close);
+
+    UseModalState {
+        active: is_active,
+        portal,
+        node,
+        open: opencb,
+        close: closecb,
+        keydown,
+    }
+}
 
-    html! {
-    <>
-        <div onclick={opencb}>
-            {props.trigger.clone()}
-        </div>
-        <div id={props.id.clone()} {class}>
-            <div class="modal-background" onclick={closecb.clone()}></div>
-            <div class="modal-card">
-                <header class="modal-card-head">
-                    <p class="modal-card-title">{props.title.clone()}</p>
-                    <button class="delete" aria-label="close" onclick={closecb.clone()}></button>
-                </header>
-                <section class="modal-card-body">
-                    {props.body.clone()}
-                </section>
-                <footer class="modal-card-foot">
-                    {props.footer.clone()}
-                </footer>
-            </div>
-            <button class="modal-close is-large" aria-label="close" onclick={closecb}></button>
-        </div>
-    </>
+/// Clip the page & move focus into the modal, returning the previously focused element.
+fn on_modal_open(node: &NodeRef) -> Option<HtmlElement> {
+    let document = web_sys::window()?.document()?;
+    let previous = document.active_element().and_then(|el| el.dyn_into::<HtmlElement>().ok());
+    if let Some(html) = document.document_element() {
+        let _ = html.class_list().add_1("is-clipped");
+    }
+    let modal = node.cast::<HtmlElement>()?;
+    let first = modal
+        .query_selector(FOCUSABLE)
+        .ok()
+        .flatten()
+        .and_then(|el| el.dyn_into::<HtmlElement>().ok());
+    let _ = first.unwrap_or(modal).focus();
+    previous
+}
+
+/// Unclip the page, unless another modal is still open, & restore focus to the given element.
+fn on_modal_close(previous: Option<HtmlElement>) {
+    if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+        let others_open = document.query_selector(".modal.is-active").ok().flatten().is_some();
+        if let (false, Some(html)) = (others_open, document.document_element()) {
+            let _ = html.class_list().remove_1("is-clipped");
+        }
+    }
+    if let Some(previous) = previous {
+        let _ = previous.focus();
+    }
+}
+
+/// Keep Tab navigation within the given modal, wrapping around at either end.
+fn trap_focus(node: &NodeRef, event: &KeyboardEvent) {
+    let Some(modal) = node.cast::<HtmlElement>() else { return };
+    let focusable: Vec<HtmlElement> = match modal.query_selector_all(FOCUSABLE) {
+        Ok(list) => (0..list.length())
+            .filter_map(|idx| list.get(idx)?.dyn_into().ok())
+            .collect(),
+        Err(_) => return,
+    };
+    let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+        event.prevent_default();
+        return;
+    };
+    let active = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.active_element());
+    let is_active = |el: &HtmlElement| active.as_ref().map(|active| active == el.as_ref()).unwrap_or(false);
+    let outside = active
+        .as_ref()
+        .map(|active| !modal.contains(Some(active)) || active == modal.as_ref())
+        .unwrap_or(true);
+    if event.shift_key() && (is_active(first) || outside) {
+        event.prevent_default();
+        let _ = last.focus();
+    } else if !event.shift_key() && (is_active(last) || outside) {
+        event.prevent_default();
+        let _ = first.focus();
     }
 }
 
 //////////////////////////////////////////////////////////////////////////////
 
-/// A request to close a modal instance by ID.
+/// The set of open modal IDs held by a `ModalProvider`.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ModalState {
+    open: HashSet<String>,
+}
+
+/// Actions on the open state of a modal, by ID.
+enum ModalAction {
+    Open(String),
+    Close(String),
+    Toggle(String),
+}
+
+impl Reducible for ModalState {
+    type Action = ModalAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let (id, value) = match action {
+            ModalAction::Open(id) => (id, true),
+            ModalAction::Close(id) => (id, false),
+            ModalAction::Toggle(id) => {
+                let value = !self.open.contains(&id);
+                (id, value)
+            }
+        };
+        if self.open.contains(&id) == value {
+            return self;
+        }
+        let mut state = (*self).clone();
+        if value {
+            state.open.insert(id);
+        } else {
+            state.open.remove(&id);
+        }
+        Rc::new(state)
+    }
+}
+
+/// The context provided by a `ModalProvider`, used to open & close modals by ID.
 ///
-/// The ID provided in this message must match the ID of the modal which is to be closed, else
-/// the message will be ignored.
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct ModalCloseMsg(pub String);
+/// Most apps will want to use the `use_modal` hook instead of consuming this context directly.
+#[derive(Clone, PartialEq)]
+pub struct ModalContext(UseReducerHandle<ModalState>);
+
+impl ModalContext {
+    /// Check if the modal with the given ID is open.
+    pub fn is_open(&self, id: &str) -> bool {
+        self.0.open.contains(id)
+    }
+
+    /// Open the modal with the given ID.
+    pub fn open(&self, id: impl Into<String>) {
+        self.0.dispatch(ModalAction::Open(id.into()));
+    }
+
+    /// Close the modal with the given ID.
+    pub fn close(&self, id: impl Into<String>) {
+        self.0.dispatch(ModalAction::Close(id.into()));
+    }
 
-/// An agent used for being able to close `Modal` & `ModalCard` instances by ID.
+    /// Toggle the modal with the given ID.
+    pub fn toggle(&self, id: impl Into<String>) {
+        self.0.dispatch(ModalAction::Toggle(id.into()));
+    }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct ModalProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// A context provider which tracks the open state of all `Modal` & `ModalCard` instances
+/// rendered within it.
 ///
-/// If custom modal closing functionality is need for your modal instance, the following
-/// pattern is recommended.
+/// Place this component near the root of your app, then use the `use_modal` hook to open, close
+/// or toggle any modal by its ID. Modals which are given the `open` prop remain controlled by
+/// their parent, and are not affected by this provider.
+#[function_component(ModalProvider)]
+pub fn modal_provider(props: &ModalProviderProps) -> Html {
+    let state = use_reducer(ModalState::default);
+    html! {
+        <ContextProvider<ModalContext> context={ModalContext(state)}>
+            {props.children.clone()}
+        </ContextProvider<ModalContext>>
+    }
+}
+
+/// A handle to a modal by ID, as returned by `use_modal`.
+#[derive(Clone, PartialEq)]
+pub struct UseModalHandle {
+    id: String,
+    ctx: ModalContext,
+}
+
+impl UseModalHandle {
+    /// The ID of the modal this handle controls.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Check if the modal is open.
+    pub fn is_open(&self) -> bool {
+        self.ctx.is_open(&self.id)
+    }
+
+    /// Open the modal.
+    pub fn open(&self) {
+        self.ctx.open(self.id.clone());
+    }
+
+    /// Close the modal.
+    pub fn close(&self) {
+        self.ctx.close(self.id.clone());
+    }
+
+    /// Toggle the modal.
+    pub fn toggle(&self) {
+        self.ctx.toggle(self.id.clone());
+    }
+}
+
+/// A hook used to open, close & toggle the `Modal` or `ModalCard` with the given ID.
 ///
-/// First, in your component which is using this modal, configure a `ModalCloser` dispatcher.
 /// ```rust
-/// use yew::agent::Dispatcher;
 /// use yew::prelude::*;
-/// // .. snip ..
-/// fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-///     let bridge = ModalCloser::dispatcher();
-///     Self { link, props, bridge }
-/// }
-/// ```
-///
-/// Next, in your component's `view` method, setup a callback to handle your component's close
-/// event. ```rust
-/// let closer = self.link.callback(|_| ModalCloseMsg("modal-0".into()));
-/// // ... snip ...
-/// <ModalCard
-///     id="modal-0"
-///     // ... snip ...
-///     footer=html!{
-///         <Button onclick=Some(closer)>{"Close"}</Button>
-///     }
-/// />
-/// ```
+/// use ybc::{use_modal, Button};
 ///
-/// Finally, in your component's `update` method, send the `ModalCloseMsg` over to the agent which
-/// will forward the message to the modal to cause it to close.
-/// ```rust
-/// fn update(&mut self, msg: Self::Message) -> ShouldRender {
-///     self.bridge.send(msg);
-///     true
+/// #[function_component(Launcher)]
+/// fn launcher() -> Html {
+///     let modal = use_modal("modal-0");
+///     let onclick = Callback::from(move |_| modal.open());
+///     html! { <Button {onclick}>{"Open"}</Button> }
 /// }
 /// ```
 ///
-/// This pattern allows you to communicate with a modal by its given ID, allowing
-/// you to close the modal from anywhere in your application.
-pub struct ModalCloser {
-    link: WorkerLink<Self>,
-    subscribers: HashSet<HandlerId>,
+/// # Panics
+/// This hook must be used within a `ModalProvider`.
+#[hook]
+pub fn use_modal(id: impl Into<String>) -> UseModalHandle {
+    let ctx = use_context::<ModalContext>().expect("use_modal must be used within a ModalProvider");
+    UseModalHandle { id: id.into(), ctx }
 }
 
-impl Worker for ModalCloser {
-    type Input = ModalCloseMsg;
-    type Message = ();
-    // The agent receives requests to close modals by ID.
-    type Output = ModalCloseMsg;
-    type Reach = Public<ModalCloser>;
+#[cfg(feature = "router")]
+mod router {
+    use std::cell::RefCell;
+
+    use super::*;
+    use yew_router::hooks::{use_location, use_navigator, use_route};
+    use yew_router::navigator::Navigator;
+    use yew_router::Routable;
+
+    use crate::router::{with_param, without_param};
 
-    // The agent forwards the input to all registered modals.
+    /// The part of the URL a modal's open state is bound to, as used by `use_route_modal`.
+    pub struct RouteModal<R> {
+        binding: RouteModalBinding<R>,
+        back: bool,
+    }
+
+    /// A function returning the parent of a route, or `None` for routes without a modal.
+    type ParentRoute<R> = dyn Fn(&R) -> Option<R>;
 
-    fn create(link: WorkerLink<Self>) -> Self {
-        Self { link, subscribers: HashSet::new() }
+    enum RouteModalBinding<R> {
+        Route(Rc<ParentRoute<R>>),
+        Query(String),
     }
 
-    fn update(&mut self, _: Self::Message) {}
+    impl<R> RouteModal<R> {
+        /// Open the modal while the current route has a parent route, as given by `parent`.
+        ///
+        /// Closing the modal replaces the URL with the parent route, e.g. `/projects/42` for a
+        /// modal at `/projects/42/edit`.
+        pub fn route(parent: impl Fn(&R) -> Option<R> + 'static) -> Self {
+            Self {
+                binding: RouteModalBinding::Route(Rc::new(parent)),
+                back: true,
+            }
+        }
 
-    fn handle_input(&mut self, msg: Self::Input, _: HandlerId) {
-        for cmp in self.subscribers.iter() {
-            self.link.respond(*cmp, msg.clone());
+        /// Open the modal while the `param` query parameter is present, e.g. `?edit=42`.
+        ///
+        /// Closing the modal replaces the URL with the current route, without that parameter.
+        pub fn query(param: impl Into<String>) -> Self {
+            Self {
+                binding: RouteModalBinding::Query(param.into()),
+                back: true,
+            }
         }
+
+        /// Always replace the URL when closing the modal, instead of navigating back.
+        pub fn replace(mut self) -> Self {
+            self.back = false;
+            self
+        }
+    }
+
+    /// A handle to a modal bound to the URL, as returned by `use_route_modal`.
+    #[derive(Clone, PartialEq)]
+    pub struct UseRouteModalHandle {
+        open: bool,
+        value: Option<String>,
+        show: Callback<String>,
+        close: Callback<()>,
+    }
+
+    impl UseRouteModalHandle {
+        /// Check if the modal is open, for the `open` prop of the modal.
+        pub fn is_open(&self) -> bool {
+            self.open
+        }
+
+        /// The value of the query parameter the modal is bound to, if present.
+        pub fn value(&self) -> Option<&str> {
+            self.value.as_deref()
+        }
+
+        /// Open a modal bound to a query parameter, by navigating to the current route with the
+        /// parameter set to `value`. Modals bound to a route are opened by linking to that route.
+        pub fn open(&self, value: impl Into<String>) {
+            self.show.emit(value.into());
+        }
+
+        /// Close the modal.
+        pub fn close(&self) {
+            self.close.emit(());
+        }
+
+        /// The callback closing the modal, for the `on_close` prop of the modal.
+        pub fn on_close(&self) -> Callback<()> {
+            self.close.clone()
+        }
+    }
+
+    /// A hook binding the open state of a `Modal` or `ModalCard` to the current route or to a query
+    /// parameter of the URL, so that the modal can be deep linked & is closed by the back button.
+    ///
+    /// Pass the state of the returned handle to the `open` & `on_close` props of the modal.
+    /// Closing the modal navigates back when it was opened by navigating within the app, so that
+    /// the modal's URL does not remain in the history. When the modal was opened by loading its
+    /// URL, or when `RouteModal::replace` is used, the URL is replaced instead.
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_router::prelude::*;
+    /// use ybc::{use_route_modal, ModalCard, RouteModal};
+    ///
+    /// #[derive(Clone, PartialEq, Routable)]
+    /// enum Route {
+    ///     #[at("/projects/:id")]
+    ///     Project { id: u32 },
+    ///     #[at("/projects/:id/edit")]
+    ///     EditProject { id: u32 },
+    /// }
+    ///
+    /// #[function_component(EditProject)]
+    /// fn edit_project() -> Html {
+    ///     let modal = use_route_modal(RouteModal::route(|route: &Route| match route {
+    ///         Route::EditProject { id } => Some(Route::Project { id: *id }),
+    ///         _ => None,
+    ///     }));
+    ///     html! {
+    ///         <ModalCard id="edit-project" title="Edit project" open={modal.is_open()} on_close={modal.on_close()} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// This hook must be used within a yew-router `Router`.
+    #[hook]
+    pub fn use_route_modal<R>(modal: RouteModal<R>) -> UseRouteModalHandle
+    where
+        R: Routable + 'static,
+    {
+        let navigator = use_navigator().expect("use_route_modal must be used within a Router");
+        let location = use_location().expect("use_route_modal must be used within a Router");
+        let route = use_route::<R>();
+        let params = location.query::<Vec<(String, String)>>().unwrap_or_default();
+
+        // The route to replace the URL with when closing the modal, if it is open.
+        let (value, parent) = match &modal.binding {
+            RouteModalBinding::Route(parent) => (
+                None,
+                route
+                    .as_ref()
+                    .and_then(|route| parent(route))
+                    .map(|parent| (parent, vec![])),
+            ),
+            RouteModalBinding::Query(param) => {
+                let value = params
+                    .iter()
+                    .find(|(name, _)| name == param)
+                    .map(|(_, value)| value.clone());
+                let parent = value
+                    .as_ref()
+                    .and(route.clone())
+                    .map(|route| (route, without_param(&params, param)));
+                (value, parent)
+            }
+        };
+        let open = parent.is_some();
+
+        // Whether the modal has been open since this hook was first rendered, i.e. was opened by
+        // loading its URL, in which case navigating back would leave the app.
+        let opened_on_load = use_mut_ref(|| open);
+        if !open {
+            *opened_on_load.borrow_mut() = false;
+        }
+
+        let show = {
+            let (navigator, route) = (navigator.clone(), route);
+            let param = match &modal.binding {
+                RouteModalBinding::Query(param) => Some(param.clone()),
+                RouteModalBinding::Route(_) => None,
+            };
+            Callback::from(move |value: String| {
+                if let (Some(route), Some(param)) = (&route, &param) {
+                    // Serializing a list of string pairs never fails.
+                    let _ = navigator.push_with_query(route, &with_param(&params, param, value));
+                }
+            })
+        };
+        let close = {
+            let back = modal.back;
+            Callback::from(move |_| close_route_modal(&navigator, &parent, back, &opened_on_load))
+        };
+
+        UseRouteModalHandle { open, value, show, close }
+    }
+
+    /// Navigate away from the URL of an open modal, back or to its parent route & query.
+    fn close_route_modal<R: Routable>(
+        navigator: &Navigator, parent: &Option<(R, Vec<(String, String)>)>, back: bool, opened_on_load: &RefCell<bool>,
+    ) {
+        match parent {
+            Some(_) if back && !*opened_on_load.borrow() => navigator.back(),
+            Some((parent, params)) if params.is_empty() => navigator.replace(parent),
+            Some((parent, params)) => {
+                let _ = navigator.replace_with_query(parent, params);
+            }
+            None => (),
+        }
+    }
+}
+
+#[cfg(feature = "router")]
+pub use router::{use_route_modal, RouteModal, UseRouteModalHandle};
+
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "agent")]
+mod agent {
+    use std::collections::HashSet;
+
+    use serde::{Deserialize, Serialize};
+    use yew_agent::{HandlerId, Public, Worker, WorkerLink};
+
+    /// A request to close a modal instance by ID.
+    ///
+    /// The ID provided in this message must match the ID of the modal which is to be closed, else
+    /// the message will be ignored.
+    #[derive(Serialize, Deserialize, Clone, Debug)]
+    pub struct ModalCloseMsg(pub String);
+
+    /// An agent used for being able to close `Modal` & `ModalCard` instances by ID.
+    ///
+    /// If custom modal closing functionality is need for your modal instance, the following
+    /// pattern is recommended.
+    ///
+    /// First, in your component which is using this modal, configure a `ModalCloser` dispatcher.
+    /// ```rust,ignore
+    /// use yew::agent::Dispatcher;
+    /// use yew::prelude::*;
+    /// // .. snip ..
+    /// fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+    ///     let bridge = ModalCloser::dispatcher();
+    ///     Self { link, props, bridge }
+    /// }
+    /// ```
+    ///
+    /// Next, in your component's `view` method, setup a callback to handle your component's close
+    /// event.
+    /// ```rust,ignore
+    /// let closer = self.link.callback(|_| ModalCloseMsg("modal-0".into()));
+    /// // ... snip ...
+    /// <ModalCard
+    ///     id="modal-0"
+    ///     // ... snip ...
+    ///     footer=html!{
+    ///         <Button onclick=Some(closer)>{"Close"}</Button>
+    ///     }
+    /// />
+    /// ```
+    ///
+    /// Finally, in your component's `update` method, send the `ModalCloseMsg` over to the agent which
+    /// will forward the message to the modal to cause it to close.
+    /// ```rust,ignore
+    /// fn update(&mut self, msg: Self::Message) -> ShouldRender {
+    ///     self.bridge.send(msg);
+    ///     true
+    /// }
+    /// ```
+    ///
+    /// This pattern allows you to communicate with a modal by its given ID, allowing
+    /// you to close the modal from anywhere in your application.
+    ///
+    /// This agent requires the `agent` feature. Prefer the `ModalProvider` & `use_modal` hook,
+    /// which do not require a separate worker bundle.
+    pub struct ModalCloser {
+        link: WorkerLink<Self>,
+        subscribers: HashSet<HandlerId>,
+    }
+
+    impl Worker for ModalCloser {
+        type Input = ModalCloseMsg;
+        type Message = ();
+        // The agent receives requests to close modals by ID.
+        type Output = ModalCloseMsg;
+        type Reach = Public<ModalCloser>;
+
+        // The agent forwards the input to all registered modals.
+
+        fn create(link: WorkerLink<Self>) -> Self {
+            Self { link, subscribers: HashSet::new() }
+        }
+
+        fn update(&mut self, _: Self::Message) {}
+
+        fn handle_input(&mut self, msg: Self::Input, _: HandlerId) {
+            for cmp in self.subscribers.iter() {
+                self.link.respond(*cmp, msg.clone());
+            }
+        }
+
+        fn connected(&mut self, id: HandlerId) {
+            self.subscribers.insert(id);
+        }
+
+        fn disconnected(&mut self, id: HandlerId) {
+            self.subscribers.remove(&id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reduce(state: Rc<ModalState>, action: ModalAction) -> Rc<ModalState> {
+        state.reduce(action)
+    }
+
+    #[test]
+    fn modal_state_opens_and_closes_by_id() {
+        let state = reduce(Rc::default(), ModalAction::Open("a".into()));
+        let state = reduce(state, ModalAction::Open("b".into()));
+        assert!(state.open.contains("a") && state.open.contains("b"));
+
+        let state = reduce(state, ModalAction::Close("a".into()));
+        assert!(!state.open.contains("a") && state.open.contains("b"));
     }
 
-    fn connected(&mut self, id: HandlerId) {
-        self.subscribers.insert(id);
+    #[test]
+    fn modal_state_toggles_by_id() {
+        let state = reduce(Rc::default(), ModalAction::Toggle("a".into()));
+        assert!(state.open.contains("a"));
+        let state = reduce(state, ModalAction::Toggle("a".into()));
+        assert!(state.open.is_empty());
     }
 
-    fn disconnected(&mut self, id: HandlerId) {
-        self.subscribers.remove(&id);
+    #[test]
+    fn modal_state_is_unchanged_by_redundant_actions() {
+        let state = reduce(Rc::default(), ModalAction::Open("a".into()));
+        let next = reduce(state.clone(), ModalAction::Open("a".into()));
+        assert!(Rc::ptr_eq(&state, &next));
+        let next = reduce(state.clone(), ModalAction::Close("b".into()));
+        assert!(Rc::ptr_eq(&state, &next));
     }
 }
@@ -1,11 +1,17 @@
+#![allow(non_local_definitions)]
+
 use derive_more::Display;
 use yew::prelude::*;
 
 use crate::components::dropdown::DropdownMsg;
+use crate::Color;
 
 /// The message type used by the `Navbar` component.
 pub enum NavbarMsg {
+    /// Open the burger menu if closed, or close it if open.
     ToggleMenu,
+    /// Close the burger menu if open.
+    CloseMenu,
 }
 
 #[derive(Clone, Debug, Properties, PartialEq)]
@@ -17,6 +23,9 @@ pub struct NavbarProps {
     /// Make the navbar fixed to the top or bottom of the UI.
     #[prop_or_default]
     pub fixed: Option<NavbarFixed>,
+    /// The color of this navbar.
+    #[prop_or_default]
+    pub color: Option<Color>,
     /// Seamlessly integrate the navbar in any visual context.
     ///
     /// [https://bulma.io/documentation/components/navbar/#transparent-navbar](https://bulma.io/documentation/components/navbar/#transparent-navbar)
@@ -54,16 +63,40 @@ pub struct NavbarProps {
 /// A responsive horizontal navbar that can support images, links, buttons, and dropdowns.
 ///
 /// [https://bulma.io/documentation/components/navbar/](https://bulma.io/documentation/components/navbar/)
+///
+/// With the `router` feature, the burger menu is closed whenever the router navigates.
 pub struct Navbar {
     is_menu_open: bool,
+    /// The context provided to the items of this navbar.
+    context: NavbarContext,
+    #[cfg(feature = "router")]
+    _location_listener: Option<yew_router::scope_ext::LocationHandle>,
+}
+
+/// The context provided by a `Navbar` to its items.
+#[derive(Clone, PartialEq)]
+pub(crate) struct NavbarContext {
+    /// The callback used for closing the burger menu.
+    pub close_menu: Callback<()>,
 }
 
 impl Component for Navbar {
     type Message = NavbarMsg;
     type Properties = NavbarProps;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self { is_menu_open: false }
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            is_menu_open: false,
+            context: NavbarContext {
+                close_menu: ctx.link().callback(|_| NavbarMsg::CloseMenu),
+            },
+            #[cfg(feature = "router")]
+            _location_listener: {
+                use yew_router::scope_ext::RouterScopeExt;
+                ctx.link()
+                    .add_location_listener(ctx.link().callback(|_| NavbarMsg::CloseMenu))
+            },
+        }
     }
 
     fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -71,6 +104,12 @@ impl Component for Navbar {
             NavbarMsg::ToggleMenu => {
                 self.is_menu_open = !self.is_menu_open;
             }
+            NavbarMsg::CloseMenu => {
+                if !self.is_menu_open {
+                    return false;
+                }
+                self.is_menu_open = false;
+            }
         }
         true
     }
@@ -80,7 +119,16 @@ impl Component for Navbar {
         let mut class = Classes::from("navbar");
         class.push(ctx.props().classes.clone());
         if let Some(fixed) = &ctx.props().fixed {
-            class.push(&fixed.to_string());
+            class.push(fixed.to_string());
+        }
+        if let Some(color) = &ctx.props().color {
+            class.push(color.to_string());
+        }
+        if ctx.props().transparent {
+            class.push("is-transparent");
+        }
+        if ctx.props().spaced {
+            class.push("is-spaced");
         }
 
         // navbar-menu classes
@@ -135,16 +183,17 @@ impl Component for Navbar {
             </>
         };
 
-        if ctx.props().padded {
-            html! {
-                <nav {class} role="navigation" aria-label="main navigation">
-                    <div class="container">{contents}</div>
-                </nav>
-            }
+        let contents = if ctx.props().padded {
+            html! {<div class="container">{contents}</div>}
         } else {
-            html! {
-                <nav {class} role="navigation" aria-label="main navigation">{contents}</nav>
-            }
+            contents
+        };
+        html! {
+            <nav {class} role="navigation" aria-label="main navigation">
+                <ContextProvider<NavbarContext> context={self.context.clone()}>
+                    {contents}
+                </ContextProvider<NavbarContext>>
+            </nav>
         }
     }
 }
@@ -247,6 +296,80 @@ pub fn navbar_item(props: &NavbarItemProps) -> Html {
     }
 }
 
+#[cfg(feature = "router")]
+mod router {
+    use super::*;
+    use serde::Serialize;
+    use yew_router::hooks::use_navigator;
+    use yew_router::Routable;
+
+    use crate::router::{route_href, route_onclick, use_route_active, RouteMatch};
+
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct NavbarItemRouterProps<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + 'static = ()> {
+        /// The Switched item representing the route.
+        pub route: R,
+        /// The query parameters appended to the route, serialized as a query string.
+        #[prop_or_default]
+        pub query: Option<Q>,
+        #[prop_or_default]
+        pub children: Children,
+        #[prop_or_default]
+        pub classes: Classes,
+        /// How the current location is matched against the route, to mark this item as active.
+        #[prop_or_default]
+        pub route_match: RouteMatch,
+        /// Turn this into a full-width element.
+        #[prop_or_default]
+        pub expanded: bool,
+        /// Add a bottom border on hover, shown while this item is active.
+        #[prop_or_default]
+        pub tab: bool,
+    }
+
+    /// A navbar item linking to a route via the Yew Router.
+    ///
+    /// [https://bulma.io/documentation/components/navbar/#navbar-item](https://bulma.io/documentation/components/navbar/#navbar-item)
+    ///
+    /// The item is marked with `is-active` & `aria-current="page"` while the current location
+    /// matches its route, according to `route_match`. Clicking the item closes the burger menu of
+    /// its `Navbar`. This component must be rendered within a yew-router `Router`.
+    #[function_component(NavbarItemRouter)]
+    pub fn navbar_item_router<R, Q = ()>(props: &NavbarItemRouterProps<R, Q>) -> Html
+    where
+        R: Routable + Clone + PartialEq + 'static,
+        Q: Clone + PartialEq + Serialize + 'static,
+    {
+        let navigator = use_navigator().expect("NavbarItemRouter must be used within a Router");
+        let active = use_route_active(&props.route, props.route_match);
+        let navbar = use_context::<NavbarContext>();
+
+        let href = route_href(&navigator, &props.route, props.query.as_ref());
+        let navigate = route_onclick(navigator, props.route.clone(), props.query.clone());
+        let onclick = Callback::from(move |event| {
+            navigate.emit(event);
+            if let Some(navbar) = &navbar {
+                navbar.close_menu.emit(());
+            }
+        });
+        let class = classes!(
+            "navbar-item",
+            props.classes.clone(),
+            props.expanded.then_some("is-expanded"),
+            props.tab.then_some("is-tab"),
+            active.then_some("is-active"),
+        );
+        html! {
+            <a {class} {href} aria-current={active.then_some("page")} {onclick}>
+                {props.children.clone()}
+            </a>
+        }
+    }
+}
+
+#[cfg(feature = "router")]
+pub use router::{NavbarItemRouter, NavbarItemRouterProps};
+
 //////////////////////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////////////////////
 
@@ -1,3 +1,5 @@
+#![allow(non_local_definitions)]
+
 use derive_more::Display;
 use yew::events::MouseEvent;
 use yew::prelude::*;
@@ -21,17 +23,128 @@ pub struct PaginationProps {
     #[prop_or_default]
     pub rounded: bool,
 
-    /// The `pagination-previous` element to use.
+    /// The `pagination-previous` element to use, unless `total_pages` is given.
+    #[prop_or_default]
     pub previous: Html,
-    /// The `pagination-next` element to use.
+    /// The `pagination-next` element to use, unless `total_pages` is given.
+    #[prop_or_default]
     pub next: Html,
+
+    /// The total number of pages. When given, the pagination elements are rendered from the
+    /// `current` page, `siblings` & `boundaries` props rather than from the children.
+    #[prop_or_default]
+    pub total_pages: Option<usize>,
+    /// The current page, starting at 1.
+    #[prop_or(1)]
+    pub current: usize,
+    /// The number of pages rendered on each side of the current page.
+    #[prop_or(1)]
+    pub siblings: usize,
+    /// The number of pages rendered at the start & at the end of the pagination.
+    #[prop_or(1)]
+    pub boundaries: usize,
+    /// The callback invoked with the page to navigate to.
+    #[prop_or_default]
+    pub on_page_change: Callback<usize>,
+    /// The callback used for building the `href` of the link to the given page. When given, the
+    /// default action of clicking a link is prevented, leaving navigation to `on_page_change`.
+    #[prop_or_default]
+    pub page_href: Option<Callback<usize, String>>,
+    /// The label of the generated `pagination-previous` element.
+    #[prop_or_else(|| "Previous".into())]
+    pub previous_label: String,
+    /// The label of the generated `pagination-next` element.
+    #[prop_or_else(|| "Next".into())]
+    pub next_label: String,
 }
 
 /// A responsive, usable, and flexible pagination component.
 ///
 /// [https://bulma.io/documentation/components/pagination/](https://bulma.io/documentation/components/pagination/)
+///
+/// When `total_pages` is given, the pagination elements are generated: the first & last pages, the
+/// pages around the `current` page, ellipses for the pages in between, & previous & next buttons
+/// which are disabled on the first & last pages.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use ybc::Pagination;
+///
+/// #[function_component(Pages)]
+/// fn pages() -> Html {
+///     let page = use_state(|| 1);
+///     let on_page_change = {
+///         let page = page.clone();
+///         Callback::from(move |target| page.set(target))
+///     };
+///     html! { <Pagination current={*page} total_pages={Some(20)} {on_page_change} /> }
+/// }
+/// ```
 #[function_component(Pagination)]
 pub fn pagination(props: &PaginationProps) -> Html {
+    if let Some(total_pages) = props.total_pages {
+        return pagination_pages(props, total_pages);
+    }
+    pagination_nav(
+        props,
+        props.previous.clone(),
+        props.next.clone(),
+        props.children.clone().into_iter().collect(),
+    )
+}
+
+/// The generated elements of a `Pagination` with the given number of pages.
+fn pagination_pages(props: &PaginationProps, total_pages: usize) -> Html {
+    let current = props.current.clamp(1, total_pages.max(1));
+    let item = |target: usize, item_type: PaginationItemType, label: String, children: Html, disabled: bool| {
+        let href = props
+            .page_href
+            .as_ref()
+            .filter(|_| !disabled)
+            .map(|page_href| AttrValue::from(page_href.emit(target)));
+        let prevent_default = href.is_some();
+        let onclick = props.on_page_change.reform(move |event: MouseEvent| {
+            if prevent_default {
+                event.prevent_default();
+            }
+            target
+        });
+        let is_current = item_type == PaginationItemType::Link && target == current;
+        html! {
+            <PaginationItem {item_type} {label} {href} current={is_current} {disabled} {onclick}>
+                {children}
+            </PaginationItem>
+        }
+    };
+    let previous = item(
+        current.saturating_sub(1),
+        PaginationItemType::Previous,
+        "Goto previous page".into(),
+        html! {props.previous_label.clone()},
+        current <= 1,
+    );
+    let next = item(
+        current + 1,
+        PaginationItemType::Next,
+        "Goto next page".into(),
+        html! {props.next_label.clone()},
+        current >= total_pages,
+    );
+    let pages = pagination_window(current, total_pages, props.siblings, props.boundaries)
+        .into_iter()
+        .map(|page| match page {
+            PaginationPage::Page(target) => {
+                let link = item(target, PaginationItemType::Link, format!("Goto page {}", target), html! {target}, false);
+                html! {<li>{link}</li>}
+            }
+            PaginationPage::Ellipsis => html! {<li><PaginationEllipsis /></li>},
+        })
+        .collect();
+    pagination_nav(props, previous, next, pages)
+}
+
+/// The `nav` element of a `Pagination`, holding the given previous & next elements & list items.
+fn pagination_nav(props: &PaginationProps, previous: Html, next: Html, items: Html) -> Html {
     let class = classes!(
         "pagination",
         props.classes.clone(),
@@ -41,15 +154,58 @@ pub fn pagination(props: &PaginationProps) -> Html {
     );
     html! {
         <nav {class} role="navigation" aria-label="pagination">
-            {props.previous.clone()}
-            {props.next.clone()}
+            {previous}
+            {next}
             <ul class="pagination-list">
-                {props.children.clone()}
+                {items}
             </ul>
         </nav>
     }
 }
 
+/// An entry of the list of pages of a `Pagination`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PaginationPage {
+    /// A link to the page with the given number.
+    Page(usize),
+    /// An ellipsis standing for the pages omitted between two links.
+    Ellipsis,
+}
+
+/// The list of pages of a pagination of `total` pages: the `boundaries` first & last pages, the
+/// `siblings` pages on each side of the `current` page, & ellipses for the omitted pages.
+///
+/// The number of entries is constant for a given `total`, so that the links do not move around as
+/// the current page changes; an ellipsis standing for a single page is replaced by that page.
+fn pagination_window(current: usize, total: usize, siblings: usize, boundaries: usize) -> Vec<PaginationPage> {
+    let (current, total, siblings, boundaries) = (current as isize, total as isize, siblings as isize, boundaries as isize);
+    let range = |start: isize, end: isize| (start..=end).map(|page| PaginationPage::Page(page as usize));
+
+    let start_pages_end = boundaries.min(total);
+    let end_pages_start = (total - boundaries + 1).max(boundaries + 1);
+    let siblings_start = (current - siblings)
+        .min(total - boundaries - siblings * 2 - 1)
+        .max(boundaries + 2);
+    let siblings_end = (current + siblings)
+        .max(boundaries + siblings * 2 + 2)
+        .min(if end_pages_start <= total { end_pages_start - 2 } else { total - 1 });
+
+    let mut pages: Vec<_> = range(1, start_pages_end).collect();
+    if siblings_start > boundaries + 2 {
+        pages.push(PaginationPage::Ellipsis);
+    } else if boundaries + 1 < total - boundaries {
+        pages.extend(range(boundaries + 1, boundaries + 1));
+    }
+    pages.extend(range(siblings_start, siblings_end));
+    if siblings_end < total - boundaries - 1 {
+        pages.push(PaginationPage::Ellipsis);
+    } else if total - boundaries > boundaries {
+        pages.extend(range(total - boundaries, total - boundaries));
+    }
+    pages.extend(range(end_pages_start, total));
+    pages
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////////////////////
 
@@ -64,6 +220,16 @@ pub struct PaginationItemProps {
     /// The click handler for this component.
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
+    /// The URL this element links to.
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Mark this element as the link to the current page.
+    #[prop_or_default]
+    pub current: bool,
+    /// Disable this element, e.g. a previous button on the first page; its click handler is not
+    /// invoked.
+    #[prop_or_default]
+    pub disabled: bool,
 }
 
 /// A pagination element representing a link to a page number, the previous page or the next page.
@@ -71,8 +237,17 @@ pub struct PaginationItemProps {
 /// [https://bulma.io/documentation/components/pagination/](https://bulma.io/documentation/components/pagination/)
 #[function_component(PaginationItem)]
 pub fn pagination_item(props: &PaginationItemProps) -> Html {
+    let class = classes!(props.item_type.to_string(), props.current.then_some("is-current"));
+    let onclick = (!props.disabled).then(|| props.onclick.clone());
     html! {
-        <a class={props.item_type.to_string()} aria-label={props.label.clone()} onclick={props.onclick.clone()}>
+        <a
+            {class}
+            href={props.href.clone()}
+            aria-label={props.label.clone()}
+            aria-current={props.current.then_some("page")}
+            disabled={props.disabled}
+            {onclick}
+        >
             {props.children.clone()}
         </a>
     }
@@ -123,9 +298,12 @@ mod router {
     use yew_router::Routable;
 
     #[derive(Clone, Properties, PartialEq)]
-    pub struct RouterProps<R: Routable + Clone + PartialEq + 'static> {
+    pub struct PaginationItemRouterProps<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + 'static = ()> {
         /// The Switched item representing the route.
         pub route: R,
+        /// The query parameters appended to the route, serialized as a query string.
+        #[prop_or_default]
+        pub query: Option<Q>,
         /// Html inside the component.
         #[prop_or_default]
         pub children: Children,
@@ -141,7 +319,7 @@ mod router {
 
     impl<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static> Component for PaginationItemRouter<R, Q> {
         type Message = ();
-        type Properties = RouterProps<R>;
+        type Properties = PaginationItemRouterProps<R, Q>;
 
         fn create(_ctx: &Context<Self>) -> Self {
             Self {
@@ -154,6 +332,7 @@ mod router {
             html! {
                 <Link<R, Q>
                     to={ctx.props().route.clone()}
+                    query={ctx.props().query.clone()}
                     children={ctx.props().children.clone()}
                     classes={classes!(ctx.props().item_type.to_string())}
                 />
@@ -163,4 +342,138 @@ mod router {
 }
 
 #[cfg(feature = "router")]
-pub use router::PaginationItemRouter;
+mod query {
+    use super::*;
+    use yew_router::hooks::{use_location, use_navigator, use_route};
+    use yew_router::Routable;
+
+    use crate::router::{route_href, with_param};
+
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct PaginationRouterProps<R: Routable + Clone + PartialEq + 'static> {
+        /// The total number of pages.
+        pub total_pages: usize,
+        /// The name of the query parameter holding the current page; defaults to `"page"`.
+        #[prop_or_else(|| "page".into())]
+        pub param: String,
+        /// The route the page links point to; defaults to the current route.
+        #[prop_or_default]
+        pub route: Option<R>,
+        #[prop_or_default]
+        pub classes: Classes,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// The alignment of this component.
+        #[prop_or_default]
+        pub alignment: Option<Alignment>,
+        /// Make the pagination elements rounded.
+        #[prop_or_default]
+        pub rounded: bool,
+        /// The number of pages rendered on each side of the current page.
+        #[prop_or(1)]
+        pub siblings: usize,
+        /// The number of pages rendered at the start & at the end of the pagination.
+        #[prop_or(1)]
+        pub boundaries: usize,
+        /// The label of the `pagination-previous` element.
+        #[prop_or_else(|| "Previous".into())]
+        pub previous_label: String,
+        /// The label of the `pagination-next` element.
+        #[prop_or_else(|| "Next".into())]
+        pub next_label: String,
+    }
+
+    /// A `Pagination` whose current page is stored in a query parameter of the URL, e.g. `?page=3`.
+    ///
+    /// The current page is read from the `param` query parameter of the current location, & each
+    /// page is linked to via the same route with that parameter updated, keeping all other query
+    /// parameters unchanged. Pages are navigated to via yew-router, without reloading the page.
+    ///
+    /// This component must be rendered within a yew-router `Router`. Nothing is rendered when no
+    /// `route` is given & the current location does not match any route.
+    #[function_component(PaginationRouter)]
+    pub fn pagination_router<R: Routable + Clone + PartialEq + 'static>(props: &PaginationRouterProps<R>) -> Html {
+        let navigator = use_navigator().expect("PaginationRouter must be used within a Router");
+        let location = use_location().expect("PaginationRouter must be used within a Router");
+        let current_route = use_route::<R>();
+        let route = match props.route.clone().or(current_route) {
+            Some(route) => route,
+            None => return html! {},
+        };
+
+        let params = location.query::<Vec<(String, String)>>().unwrap_or_default();
+        let current = params
+            .iter()
+            .find(|(name, _)| *name == props.param)
+            .and_then(|(_, value)| value.parse().ok())
+            .unwrap_or(1);
+
+        let page_href = {
+            let (navigator, route, params, param) = (navigator.clone(), route.clone(), params.clone(), props.param.clone());
+            Callback::from(move |page: usize| route_href(&navigator, &route, Some(&with_param(&params, &param, page.to_string()))))
+        };
+        let on_page_change = {
+            let param = props.param.clone();
+            Callback::from(move |page: usize| {
+                // Serializing a list of string pairs never fails.
+                let _ = navigator.push_with_query(&route, &with_param(&params, &param, page.to_string()));
+            })
+        };
+
+        html! {
+            <Pagination
+                classes={props.classes.clone()}
+                size={props.size.clone()}
+                alignment={props.alignment.clone()}
+                rounded={props.rounded}
+                total_pages={Some(props.total_pages)}
+                {current}
+                siblings={props.siblings}
+                boundaries={props.boundaries}
+                {on_page_change}
+                page_href={Some(page_href)}
+                previous_label={props.previous_label.clone()}
+                next_label={props.next_label.clone()}
+            />
+        }
+    }
+}
+
+#[cfg(feature = "router")]
+pub use query::{PaginationRouter, PaginationRouterProps};
+#[cfg(feature = "router")]
+pub use router::{PaginationItemRouter, PaginationItemRouterProps};
+
+#[cfg(test)]
+mod tests {
+    use super::PaginationPage::{Ellipsis, Page as P};
+    use super::*;
+
+    #[test]
+    fn pagination_window_of_few_pages() {
+        assert_eq!(pagination_window(1, 0, 1, 1), vec![]);
+        assert_eq!(pagination_window(1, 1, 1, 1), vec![P(1)]);
+        assert_eq!(pagination_window(2, 5, 1, 1), vec![P(1), P(2), P(3), P(4), P(5)]);
+        assert_eq!(pagination_window(4, 7, 1, 1), vec![P(1), P(2), P(3), P(4), P(5), P(6), P(7)]);
+    }
+
+    #[test]
+    fn pagination_window_around_the_current_page() {
+        assert_eq!(pagination_window(1, 10, 1, 1), vec![P(1), P(2), P(3), P(4), P(5), Ellipsis, P(10)]);
+        assert_eq!(pagination_window(4, 10, 1, 1), vec![P(1), P(2), P(3), P(4), P(5), Ellipsis, P(10)]);
+        assert_eq!(pagination_window(5, 10, 1, 1), vec![P(1), Ellipsis, P(4), P(5), P(6), Ellipsis, P(10)]);
+        assert_eq!(pagination_window(7, 10, 1, 1), vec![P(1), Ellipsis, P(6), P(7), P(8), P(9), P(10)]);
+        assert_eq!(pagination_window(10, 10, 1, 1), vec![P(1), Ellipsis, P(6), P(7), P(8), P(9), P(10)]);
+    }
+
+    #[test]
+    fn pagination_window_with_siblings_and_boundaries() {
+        assert_eq!(
+            pagination_window(10, 20, 2, 2),
+            vec![P(1), P(2), Ellipsis, P(8), P(9), P(10), P(11), P(12), Ellipsis, P(19), P(20)]
+        );
+        assert_eq!(pagination_window(10, 20, 0, 0), vec![Ellipsis, P(10), Ellipsis]);
+        assert_eq!(pagination_window(1, 20, 0, 1), vec![P(1), P(2), P(3), Ellipsis, P(20)]);
+    }
+}
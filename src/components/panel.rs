@@ -1,6 +1,9 @@
 use yew::events::MouseEvent;
 use yew::prelude::*;
 
+use crate::components::virtual_scroll::use_virtual_window;
+use crate::Virtualize;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct PanelProps {
     #[prop_or_default]
@@ -10,6 +13,10 @@ pub struct PanelProps {
     /// The HTML content of this panel's heading; it is automatically wrapped in a `p.panel-heading`.
     #[prop_or_default]
     pub heading: Html,
+    /// Render a list of blocks after the children of this panel via virtual scrolling, which only
+    /// renders the blocks within the viewport of the list.
+    #[prop_or_default]
+    pub virtualize: Option<Virtualize>,
 }
 
 /// A composable panel, for compact controls.
@@ -17,11 +24,25 @@ pub struct PanelProps {
 /// [https://bulma.io/documentation/components/panel/](https://bulma.io/documentation/components/panel/)
 #[function_component(Panel)]
 pub fn panel(props: &PanelProps) -> Html {
+    let window = use_virtual_window(props.virtualize.clone());
     let class = classes!("panel", props.classes.clone());
+    let blocks = props.virtualize.as_ref().map(|virtualize| {
+        let blocks = window.render(virtualize, |height| {
+            html! {
+                <div aria-hidden="true" style={format!("height: {}px;", height)}></div>
+            }
+        });
+        html! {
+            <div style={window.style.clone()} ref={window.scroller.clone()} onscroll={window.onscroll.clone()}>
+                <div ref={window.items.clone()}>{blocks}</div>
+            </div>
+        }
+    });
     html! {
         <nav {class}>
             <p class="panel-heading">{props.heading.clone()}</p>
             {props.children.clone()}
+            {blocks}
         </nav>
     }
 }
@@ -73,3 +94,62 @@ pub fn panel_block(props: &PanelBlockProps) -> Html {
         </@>
     }
 }
+
+#[cfg(feature = "router")]
+mod router {
+    use super::*;
+    use serde::Serialize;
+    use yew_router::components::Link;
+    use yew_router::Routable;
+
+    #[derive(Clone, Properties, PartialEq)]
+    pub struct PanelBlockRouterProps<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + 'static = ()> {
+        /// The Switched item representing the route.
+        pub route: R,
+        /// The query parameters appended to the route, serialized as a query string.
+        #[prop_or_default]
+        pub query: Option<Q>,
+        /// Html inside the component.
+        #[prop_or_default]
+        pub children: Children,
+        /// Make this element the active / highlighted element.
+        #[prop_or_default]
+        pub active: bool,
+    }
+
+    /// A Yew Router link rendered as an individual element of the panel.
+    ///
+    /// [https://bulma.io/documentation/components/panel/](https://bulma.io/documentation/components/panel/)
+    pub struct PanelBlockRouter<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static = ()> {
+        _route: std::marker::PhantomData<R>,
+        _query: std::marker::PhantomData<Q>,
+    }
+
+    impl<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static> Component for PanelBlockRouter<R, Q> {
+        type Message = ();
+        type Properties = PanelBlockRouterProps<R, Q>;
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self {
+                _route: std::marker::PhantomData,
+                _query: std::marker::PhantomData,
+            }
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            let props = ctx.props();
+            let classes = classes!("panel-block", props.active.then_some("is-active"));
+            html! {
+                <Link<R, Q>
+                    to={props.route.clone()}
+                    query={props.query.clone()}
+                    {classes}
+                    children={props.children.clone()}
+                />
+            }
+        }
+    }
+}
+
+#[cfg(feature = "router")]
+pub use router::{PanelBlockRouter, PanelBlockRouterProps};
@@ -26,14 +26,17 @@ pub struct TabsProps {
     /// Make this component fullwidth.
     #[prop_or_default]
     pub fullwidth: bool,
+    /// Wrap the tab list in a `container`, as in the `hero-foot` tabs layout.
+    #[prop_or_default]
+    pub container: bool,
 }
 
 /// Simple responsive horizontal navigation tabs, with different styles.
 ///
 /// [https://bulma.io/documentation/components/tabs/](https://bulma.io/documentation/components/tabs/)
 ///
-/// For integration with Yew Router, it is recommended that the `RouterButton` or `RouterAnchor`
-/// components be used as the individual tab elements for this component.
+/// For integration with Yew Router, use the `TabsRouter` component, which marks the tab of the
+/// current route as active.
 #[function_component(Tabs)]
 pub fn tabs(props: &TabsProps) -> Html {
     let class = classes!(
@@ -46,11 +49,149 @@ pub fn tabs(props: &TabsProps) -> Html {
         props.rounded.then_some("is-rounded"),
         props.fullwidth.then_some("is-fullwidth"),
     );
-    html! {
-        <div {class}>
-            <ul>
-                {props.children.clone()}
-            </ul>
-        </div>
+    let list = html! {
+        <ul>
+            {props.children.clone()}
+        </ul>
+    };
+    if props.container {
+        html! {
+            <div {class}>
+                <div class="container">{list}</div>
+            </div>
+        }
+    } else {
+        html! {
+            <div {class}>{list}</div>
+        }
+    }
+}
+
+#[cfg(feature = "router")]
+mod router {
+    use super::*;
+    use yew_router::hooks::use_navigator;
+    use yew_router::Routable;
+
+    use crate::router::{best_match, route_href, route_onclick, use_current_path, RouteMatch};
+    use crate::Icon;
+
+    /// A tab of a `TabsRouter`, linking to a route.
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_router::prelude::*;
+    /// use ybc::RouterTab;
+    ///
+    /// #[derive(Clone, PartialEq, Routable)]
+    /// enum Route {
+    ///     #[at("/pictures")]
+    ///     Pictures,
+    /// }
+    ///
+    /// let tab = RouterTab { icon: Some(html! {<i class="fas fa-image"></i>}), ..RouterTab::new("Pictures", Route::Pictures) };
+    /// let same_tab: RouterTab<Route> = ("Pictures", Route::Pictures, Some(html! {<i class="fas fa-image"></i>})).into();
+    /// ```
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct RouterTab<R> {
+        /// The label of the tab.
+        pub label: String,
+        /// The route the tab links to.
+        pub route: R,
+        /// The icon rendered before the label of the tab.
+        pub icon: Option<Html>,
+    }
+
+    impl<R> RouterTab<R> {
+        /// Create a tab with the given label, linking to the given route.
+        pub fn new(label: impl Into<String>, route: R) -> Self {
+            Self { label: label.into(), route, icon: None }
+        }
+    }
+
+    impl<R, L: Into<String>> From<(L, R, Option<Html>)> for RouterTab<R> {
+        fn from((label, route, icon): (L, R, Option<Html>)) -> Self {
+            Self { icon, ..Self::new(label, route) }
+        }
+    }
+
+    #[derive(Clone, Debug, Properties, PartialEq)]
+    pub struct TabsRouterProps<R: Routable + Clone + PartialEq + 'static> {
+        /// The tabs of this component.
+        pub tabs: Vec<RouterTab<R>>,
+        /// How the current location is matched against the route of each tab, to mark it as
+        /// active. Only the tab with the most specific matching route is marked as active.
+        #[prop_or_default]
+        pub route_match: RouteMatch,
+        #[prop_or_default]
+        pub classes: Classes,
+        /// The alignment of this component.
+        #[prop_or_default]
+        pub alignment: Option<Alignment>,
+        /// The size of this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+        /// Add a more classic style with borders to this component.
+        #[prop_or_default]
+        pub boxed: bool,
+        /// Add the "radio button" style to the elements of this component.
+        #[prop_or_default]
+        pub toggle: bool,
+        /// Make the tab elements of this component rounded.
+        #[prop_or_default]
+        pub rounded: bool,
+        /// Make this component fullwidth.
+        #[prop_or_default]
+        pub fullwidth: bool,
+        /// Wrap the tab list in a `container`, as in the `hero-foot` tabs layout.
+        #[prop_or_default]
+        pub container: bool,
+    }
+
+    /// Navigation tabs linking to routes via the Yew Router, marking the tab of the current route
+    /// with `is-active`.
+    ///
+    /// [https://bulma.io/documentation/components/tabs/](https://bulma.io/documentation/components/tabs/)
+    ///
+    /// For the `hero-foot` tabs layout, pass this component as the `foot` of a `Hero` with the
+    /// `container` prop set. This component must be rendered within a yew-router `Router`.
+    #[function_component(TabsRouter)]
+    pub fn tabs_router<R: Routable + Clone + PartialEq + 'static>(props: &TabsRouterProps<R>) -> Html {
+        let navigator = use_navigator().expect("TabsRouter must be used within a Router");
+        let path = use_current_path().unwrap_or_default();
+        let targets = props.tabs.iter().enumerate().map(|(idx, tab)| (idx, tab.route.to_path()));
+        let active = best_match(&path, targets, props.route_match);
+
+        let tabs = props.tabs.iter().enumerate().map(|(idx, tab)| {
+            let is_active = active == Some(idx);
+            let href = route_href::<R, ()>(&navigator, &tab.route, None);
+            let onclick = route_onclick::<R, ()>(navigator.clone(), tab.route.clone(), None);
+            let icon = tab.icon.clone().map(|icon| html! {<Icon size={Size::Small}>{icon}</Icon>});
+            html! {
+                <li class={classes!(is_active.then_some("is-active"))}>
+                    <a {href} aria-current={is_active.then_some("page")} {onclick}>
+                        {icon}
+                        <span>{tab.label.clone()}</span>
+                    </a>
+                </li>
+            }
+        });
+        html! {
+            <Tabs
+                classes={props.classes.clone()}
+                alignment={props.alignment.clone()}
+                size={props.size.clone()}
+                boxed={props.boxed}
+                toggle={props.toggle}
+                rounded={props.rounded}
+                fullwidth={props.fullwidth}
+                container={props.container}
+            >
+                {for tabs}
+            </Tabs>
+        }
     }
 }
+
+#[cfg(feature = "router")]
+pub use router::{RouterTab, TabsRouter, TabsRouterProps};
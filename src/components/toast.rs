@@ -0,0 +1,336 @@
+#![allow(non_local_definitions)]
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use derive_more::Display;
+use yew::prelude::*;
+
+use crate::elements::notification::use_dismissible;
+use crate::{Button, Color, Notification};
+
+/// A transient message shown by a `ToastProvider`.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use ybc::Toast;
+///
+/// let toast = Toast { title: Some("Saved".into()), timeout: Some(Duration::from_secs(2)), ..Toast::success("Your post was saved.") };
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Toast {
+    /// The message of this toast.
+    pub message: Html,
+    /// The title of this toast, rendered above its message.
+    pub title: Option<String>,
+    /// The color of this toast, indicating its severity.
+    pub color: Option<Color>,
+    /// The duration after which this toast is dismissed, counted from when it becomes visible;
+    /// defaults to 5 seconds. When `None`, the toast is only dismissed by the user.
+    pub timeout: Option<Duration>,
+    /// An action button rendered within this toast.
+    pub action: Option<ToastAction>,
+    /// Render a close button within this toast; defaults to `true`.
+    pub closable: bool,
+}
+
+impl Toast {
+    /// Create a toast with the given message.
+    pub fn new(message: impl Into<Html>) -> Self {
+        Self {
+            message: message.into(),
+            title: None,
+            color: None,
+            timeout: Some(Duration::from_secs(5)),
+            action: None,
+            closable: true,
+        }
+    }
+
+    /// Create an informational toast with the given message.
+    pub fn info(message: impl Into<Html>) -> Self {
+        Self { color: Some(Color::Info), ..Self::new(message) }
+    }
+
+    /// Create a success toast with the given message.
+    pub fn success(message: impl Into<Html>) -> Self {
+        Self { color: Some(Color::Success), ..Self::new(message) }
+    }
+
+    /// Create a warning toast with the given message.
+    pub fn warning(message: impl Into<Html>) -> Self {
+        Self { color: Some(Color::Warning), ..Self::new(message) }
+    }
+
+    /// Create a danger toast with the given message.
+    pub fn danger(message: impl Into<Html>) -> Self {
+        Self { color: Some(Color::Danger), ..Self::new(message) }
+    }
+}
+
+/// An action button rendered within a toast. Clicking the button also dismisses the toast.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToastAction {
+    /// The label of the button.
+    pub label: String,
+    /// The callback invoked when the button is clicked.
+    pub onclick: Callback<()>,
+}
+
+/// The ID of a toast, used for dismissing it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(usize);
+
+/// The screen corner in which a `ToastProvider` renders its toasts.
+///
+/// The toast container has the `ybc-toasts` class along with a class for its position, e.g.
+/// `ybc-toasts-top-right`. These are not Bulma classes: the container is positioned via inline
+/// styles, so no extra CSS is needed, and the classes only serve as hooks for further styling,
+/// e.g. `.ybc-toasts .notification { box-shadow: 0 0.5em 1em rgba(10, 10, 10, 0.1); }`.
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+#[display(fmt = "ybc-toasts-{}")]
+pub enum ToastPosition {
+    #[display(fmt = "top-left")]
+    TopLeft,
+    #[display(fmt = "top-right")]
+    TopRight,
+    #[display(fmt = "bottom-left")]
+    BottomLeft,
+    #[display(fmt = "bottom-right")]
+    BottomRight,
+}
+
+impl ToastPosition {
+    /// The inline style used for fixing the toast container in this corner.
+    fn style(&self) -> &'static str {
+        match self {
+            ToastPosition::TopLeft => "top: 1rem; left: 1rem;",
+            ToastPosition::TopRight => "top: 1rem; right: 1rem;",
+            ToastPosition::BottomLeft => "bottom: 1rem; left: 1rem;",
+            ToastPosition::BottomRight => "bottom: 1rem; right: 1rem;",
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// The toasts of a `ToastProvider`, in the order in which they were pushed.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ToastQueue {
+    toasts: Vec<(ToastId, Rc<Toast>)>,
+}
+
+/// Actions on the queue of toasts.
+enum ToastQueueAction {
+    Push(ToastId, Rc<Toast>),
+    Dismiss(ToastId),
+    Clear,
+}
+
+impl Reducible for ToastQueue {
+    type Action = ToastQueueAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut queue = (*self).clone();
+        match action {
+            ToastQueueAction::Push(id, toast) => queue.toasts.push((id, toast)),
+            ToastQueueAction::Dismiss(id) => queue.toasts.retain(|(toast_id, _)| *toast_id != id),
+            ToastQueueAction::Clear => queue.toasts.clear(),
+        }
+        Rc::new(queue)
+    }
+}
+
+/// The context provided by a `ToastProvider`, used to push & dismiss toasts.
+#[derive(Clone, PartialEq)]
+pub struct ToastContext {
+    queue: UseReducerHandle<ToastQueue>,
+    next_id: Rc<Cell<usize>>,
+}
+
+impl ToastContext {
+    /// Push a toast, returning its ID.
+    ///
+    /// The toast is shown immediately if fewer than `max_visible` toasts are shown, else it is
+    /// queued until enough of the shown toasts have been dismissed.
+    pub fn push(&self, toast: Toast) -> ToastId {
+        let id = ToastId(self.next_id.get());
+        self.next_id.set(id.0 + 1);
+        self.queue.dispatch(ToastQueueAction::Push(id, Rc::new(toast)));
+        id
+    }
+
+    /// Dismiss the toast with the given ID, whether it is shown or queued.
+    pub fn dismiss(&self, id: ToastId) {
+        self.queue.dispatch(ToastQueueAction::Dismiss(id));
+    }
+
+    /// Dismiss all toasts.
+    pub fn clear(&self) {
+        self.queue.dispatch(ToastQueueAction::Clear);
+    }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct ToastProviderProps {
+    #[prop_or_default]
+    pub children: Children,
+    /// The screen corner in which toasts are rendered; defaults to the top right corner.
+    #[prop_or_else(|| ToastPosition::TopRight)]
+    pub position: ToastPosition,
+    /// The maximum number of toasts shown at once; defaults to 3.
+    #[prop_or(3)]
+    pub max_visible: usize,
+}
+
+/// A context provider which renders the toasts pushed via `use_toasts`.
+///
+/// Toasts are rendered as `Notification` elements, stacked in the screen corner given by the
+/// `position` prop. Once `max_visible` toasts are shown, further toasts are queued until the shown
+/// toasts are dismissed.
+#[function_component(ToastProvider)]
+pub fn toast_provider(props: &ToastProviderProps) -> Html {
+    let queue = use_reducer(ToastQueue::default);
+    let next_id = use_memo(|_| Cell::new(0), ());
+    let context = ToastContext { queue: queue.clone(), next_id };
+    let dismiss = {
+        let queue = queue.clone();
+        Callback::from(move |id| queue.dispatch(ToastQueueAction::Dismiss(id)))
+    };
+    let style = format!(
+        "position: fixed; z-index: 50; width: 22rem; max-width: calc(100% - 2rem); {}",
+        props.position.style()
+    );
+
+    html! {
+        <ContextProvider<ToastContext> {context}>
+            {props.children.clone()}
+            <div class={classes!("ybc-toasts", props.position.to_string())} {style} aria-live="polite">
+                {for queue.toasts.iter().take(props.max_visible).map(|(id, toast)| html! {
+                    <ToastView key={id.0} id={*id} toast={toast.clone()} dismiss={dismiss.clone()} />
+                })}
+            </div>
+        </ContextProvider<ToastContext>>
+    }
+}
+
+/// A hook used to push & dismiss toasts.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use ybc::{use_toasts, Button, Toast};
+///
+/// #[function_component(SaveButton)]
+/// fn save_button() -> Html {
+///     let toasts = use_toasts();
+///     let onclick = Callback::from(move |_| {
+///         toasts.push(Toast::success("Your post was saved."));
+///     });
+///     html! { <Button {onclick}>{"Save"}</Button> }
+/// }
+/// ```
+///
+/// # Panics
+/// This hook must be used within a `ToastProvider`.
+#[hook]
+pub fn use_toasts() -> ToastContext {
+    use_context::<ToastContext>().expect("use_toasts must be used within a ToastProvider")
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+struct ToastViewProps {
+    id: ToastId,
+    toast: Rc<Toast>,
+    /// The callback used for removing the toast from the queue.
+    dismiss: Callback<ToastId>,
+}
+
+/// A single toast of a `ToastProvider`.
+#[function_component(ToastView)]
+fn toast_view(props: &ToastViewProps) -> Html {
+    let ToastViewProps { id, toast, dismiss } = props.clone();
+
+    // Every dismissal, i.e. via the close button, the action button or the timeout, fades the toast
+    // out before removing it from the queue.
+    let dismissible = use_dismissible(Some(true), dismiss.reform(move |_| id), true);
+    // Set once the toast is dismissed or unmounted, which cancels its timeout.
+    let cancelled = use_memo(|_| Cell::new(false), ());
+    let close = {
+        let (close, cancelled) = (dismissible.close.clone(), cancelled.clone());
+        Callback::from(move |_| {
+            cancelled.set(true);
+            close.emit(());
+        })
+    };
+
+    // The timeout starts once the toast is shown, rather than when it is pushed.
+    {
+        let (timeout, close) = (toast.timeout, close.clone());
+        use_effect_with_deps(
+            move |_| {
+                if let Some(timeout) = timeout {
+                    let cancelled = cancelled.clone();
+                    yew::platform::spawn_local(async move {
+                        yew::platform::time::sleep(timeout).await;
+                        if !cancelled.get() {
+                            close.emit(());
+                        }
+                    });
+                }
+                move || cancelled.set(true)
+            },
+            (),
+        );
+    }
+
+    let title = toast.title.as_ref().map(|title| {
+        html! {
+            <p><strong>{title.clone()}</strong></p>
+        }
+    });
+    let action = toast.action.as_ref().map(|action| {
+        let (onclick, close) = (action.onclick.clone(), close.clone());
+        let onclick = Callback::from(move |_| {
+            onclick.emit(());
+            close.emit(());
+        });
+        html! {
+            <Button classes={classes!("is-small", "mt-2")} {onclick}>{action.label.clone()}</Button>
+        }
+    });
+    let role = if toast.color == Some(Color::Danger) { "alert" } else { "status" };
+
+    html! {
+        <div {role} style={dismissible.style}>
+            <Notification
+                color={toast.color.clone()}
+                dismissible={toast.closable}
+                open={Some(true)}
+                on_close={close}
+            >
+                {title}
+                {toast.message.clone()}
+                {action}
+            </Notification>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toast_queue_pushes_dismisses_and_clears() {
+        let push = |id| ToastQueueAction::Push(ToastId(id), Rc::new(Toast::new("Toast")));
+        let queue = Rc::<ToastQueue>::default().reduce(push(0)).reduce(push(1)).reduce(push(2));
+        let queue = queue.reduce(ToastQueueAction::Dismiss(ToastId(1)));
+        assert_eq!(queue.toasts.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![ToastId(0), ToastId(2)]);
+
+        let queue = queue.reduce(ToastQueueAction::Clear);
+        assert!(queue.toasts.is_empty());
+    }
+}
@@ -0,0 +1,288 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+
+/// The height of the rows of a virtualized list.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowHeight {
+    /// All rows have the given height, in pixels.
+    Fixed(f64),
+    /// The rows are measured once rendered, using the given estimated height, in pixels, for the
+    /// rows which have not been rendered yet.
+    Measured(f64),
+}
+
+/// The configuration of a virtualized list, which only renders the rows within its viewport.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use ybc::{RowHeight, Virtualize};
+///
+/// let virtualize = Virtualize {
+///     height: 600.0,
+///     ..Virtualize::new(10_000, RowHeight::Fixed(40.0), |idx| html! {<tr><td>{idx}</td></tr>})
+/// };
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Virtualize {
+    /// The total number of rows.
+    pub count: usize,
+    /// The callback used for rendering the row with the given index. Each row must render exactly
+    /// one element.
+    pub render: Callback<usize, Html>,
+    /// The height of the rows.
+    pub row_height: RowHeight,
+    /// The maximum height of the viewport, in pixels; defaults to 400.
+    pub height: f64,
+    /// The number of rows rendered beyond each edge of the viewport; defaults to 5.
+    pub overscan: usize,
+}
+
+impl Virtualize {
+    /// Create the configuration of a virtualized list of `count` rows, rendered via `render`.
+    pub fn new(count: usize, row_height: RowHeight, render: impl Fn(usize) -> Html + 'static) -> Self {
+        Self {
+            count,
+            render: Callback::from(render),
+            row_height,
+            height: 400.0,
+            overscan: 5,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct VirtualScrollProps {
+    /// The rows of this component.
+    pub virtualize: Virtualize,
+    #[prop_or_default]
+    pub classes: Classes,
+}
+
+/// A scrollable container which only renders the rows within its viewport, plus an overscan
+/// margin.
+///
+/// `Table` & `Panel` accept the same `Virtualize` configuration via their `virtualize` prop.
+#[function_component(VirtualScroll)]
+pub fn virtual_scroll(props: &VirtualScrollProps) -> Html {
+    let window = use_virtual_window(Some(props.virtualize.clone()));
+    html! {
+        <div
+            class={props.classes.clone()}
+            style={window.style.clone()}
+            ref={window.scroller.clone()}
+            onscroll={window.onscroll.clone()}
+        >
+            <div ref={window.items.clone()}>
+                {window.render(&props.virtualize, |height| html! {
+                    <div aria-hidden="true" style={format!("height: {}px;", height)}></div>
+                })}
+            </div>
+        </div>
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// The rendered window of a virtualized list, as returned by `use_virtual_window`.
+pub(crate) struct VirtualWindow {
+    /// The ref of the scrolling element.
+    pub scroller: NodeRef,
+    /// The ref of the parent element of the rows, which may be the scrolling element itself.
+    pub items: NodeRef,
+    /// The scroll handler of the scrolling element.
+    pub onscroll: Callback<Event>,
+    /// The inline style of the scrolling element.
+    pub style: String,
+    range: WindowRange,
+}
+
+impl VirtualWindow {
+    /// Render the rows of the window, surrounded by spacers of the height of the rows outside of it.
+    pub fn render(&self, virtualize: &Virtualize, spacer: impl Fn(f64) -> Html) -> Html {
+        html! {
+            <>
+            {spacer(self.range.before)}
+            {for (self.range.start..self.range.end).map(|idx| virtualize.render.emit(idx))}
+            {spacer(self.range.after)}
+            </>
+        }
+    }
+}
+
+/// The rows to render of a virtualized list, along with the height of the rows before & after them.
+#[derive(Clone, Debug, PartialEq)]
+struct WindowRange {
+    start: usize,
+    end: usize,
+    before: f64,
+    after: f64,
+}
+
+/// The virtualization state shared by `VirtualScroll`, `Table` & `Panel`.
+///
+/// When `virtualize` is `None`, the returned window is empty & its callbacks do nothing.
+#[hook]
+pub(crate) fn use_virtual_window(virtualize: Option<Virtualize>) -> VirtualWindow {
+    let scroller = use_node_ref();
+    let items = use_node_ref();
+    let viewport = use_state(|| (0.0, virtualize.as_ref().map(|virtualize| virtualize.height).unwrap_or_default()));
+    let measured = use_mut_ref(Vec::<Option<f64>>::new);
+    let version = use_state(|| 0_usize);
+
+    let onscroll = {
+        let (scroller, viewport) = (scroller.clone(), viewport.clone());
+        Callback::from(move |_| {
+            if let Some(element) = scroller.cast::<Element>() {
+                let next = (element.scroll_top() as f64, element.client_height() as f64);
+                if next != *viewport {
+                    viewport.set(next);
+                }
+            }
+        })
+    };
+
+    // The offsets of measured rows are only rebuilt when the rows or their measurements change.
+    let (count, row_height) = virtualize
+        .as_ref()
+        .map_or((0, RowHeight::Fixed(0.0)), |virtualize| (virtualize.count, virtualize.row_height));
+    let offsets = {
+        let measured = measured.clone();
+        use_memo(
+            move |&(count, row_height, _)| {
+                let mut measured = measured.borrow_mut();
+                measured.resize(count, None);
+                match row_height {
+                    RowHeight::Fixed(_) => Vec::new(),
+                    RowHeight::Measured(estimate) => row_offsets(estimate, &measured),
+                }
+            },
+            (count, row_height, *version),
+        )
+    };
+    let range = match &virtualize {
+        Some(virtualize) => {
+            let (scroll_top, height) = *viewport;
+            window_range(count, row_height, &offsets, scroll_top, height, virtualize.overscan)
+        }
+        None => WindowRange { start: 0, end: 0, before: 0.0, after: 0.0 },
+    };
+
+    // Measure the rendered rows whenever they change, rendering again if any of their heights have
+    // changed.
+    {
+        let (items, measured, start, end) = (items.clone(), measured.clone(), range.start, range.end);
+        use_effect_with_deps(
+            move |_| {
+                let parent = items.cast::<Element>();
+                if let (RowHeight::Measured(_), Some(parent)) = (row_height, parent) {
+                    let children = parent.children();
+                    let mut changed = false;
+                    let mut measured = measured.borrow_mut();
+                    for idx in start..end {
+                        // The first child is the spacer before the rows.
+                        let child = children
+                            .item((idx - start + 1) as u32)
+                            .and_then(|child| child.dyn_into::<HtmlElement>().ok());
+                        if let (Some(child), Some(slot)) = (child, measured.get_mut(idx)) {
+                            let height = child.offset_height() as f64;
+                            if slot.map(|slot| (slot - height).abs() > 0.5).unwrap_or(true) {
+                                *slot = Some(height);
+                                changed = true;
+                            }
+                        }
+                    }
+                    if changed {
+                        version.set(*version + 1);
+                    }
+                }
+            },
+            (virtualize.clone(), start, end),
+        );
+    }
+
+    let style = virtualize
+        .map(|virtualize| format!("max-height: {}px; overflow-y: auto;", virtualize.height))
+        .unwrap_or_default();
+    VirtualWindow { scroller, items, onscroll, style, range }
+}
+
+/// The offsets of the rows of a list with the given measured heights, using the `estimate` for
+/// the rows which have not been measured, followed by the total height of the list.
+fn row_offsets(estimate: f64, measured: &[Option<f64>]) -> Vec<f64> {
+    let mut offsets = Vec::with_capacity(measured.len() + 1);
+    offsets.push(0.0);
+    for (idx, height) in measured.iter().enumerate() {
+        offsets.push(offsets[idx] + height.unwrap_or(estimate));
+    }
+    offsets
+}
+
+/// The rows within the given viewport of a list of `count` rows, extended by `overscan` rows on
+/// each side.
+///
+/// For `RowHeight::Measured`, the `offsets` of the rows are given as built by `row_offsets`.
+fn window_range(count: usize, row_height: RowHeight, offsets: &[f64], scroll_top: f64, viewport: f64, overscan: usize) -> WindowRange {
+    let bottom = scroll_top + viewport;
+    let (first, last, offset): (usize, usize, Box<dyn Fn(usize) -> f64 + '_>) = match row_height {
+        RowHeight::Fixed(height) => {
+            let height = height.max(1.0);
+            let first = (scroll_top / height).floor() as usize;
+            let last = (bottom / height).ceil() as usize;
+            (first, last, Box::new(move |idx| idx as f64 * height))
+        }
+        RowHeight::Measured(_) => {
+            let first = offsets[..count]
+                .partition_point(|&top| top <= scroll_top)
+                .saturating_sub(1);
+            let last = offsets.partition_point(|&top| top < bottom);
+            (first, last, Box::new(move |idx| offsets[idx]))
+        }
+    };
+    let start = first.saturating_sub(overscan).min(count);
+    let end = last.saturating_add(overscan).min(count).max(start);
+    WindowRange {
+        start,
+        end,
+        before: offset(start),
+        after: offset(count) - offset(end),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: usize, end: usize, before: f64, after: f64) -> WindowRange {
+        WindowRange { start, end, before, after }
+    }
+
+    #[test]
+    fn window_range_with_fixed_heights() {
+        let height = RowHeight::Fixed(10.0);
+        assert_eq!(window_range(1000, height, &[], 0.0, 100.0, 0), range(0, 10, 0.0, 9900.0));
+        assert_eq!(window_range(1000, height, &[], 505.0, 100.0, 2), range(48, 63, 480.0, 9370.0));
+        assert_eq!(window_range(1000, height, &[], 9950.0, 100.0, 2), range(993, 1000, 9930.0, 0.0));
+    }
+
+    #[test]
+    fn window_range_with_measured_heights() {
+        let mut measured = vec![Some(50.0), None, Some(5.0), Some(5.0)];
+        measured.resize(10, None);
+        let offsets = row_offsets(20.0, &measured);
+        assert_eq!(offsets[..6], [0.0, 50.0, 70.0, 75.0, 80.0, 100.0]);
+        let height = RowHeight::Measured(20.0);
+        assert_eq!(window_range(10, height, &offsets, 0.0, 60.0, 0), range(0, 2, 0.0, 130.0));
+        assert_eq!(window_range(10, height, &offsets, 72.0, 10.0, 0), range(2, 5, 70.0, 100.0));
+        assert_eq!(window_range(10, height, &offsets, 72.0, 10.0, 1), range(1, 6, 50.0, 80.0));
+    }
+
+    #[test]
+    fn window_range_of_an_empty_list() {
+        assert_eq!(window_range(0, RowHeight::Fixed(10.0), &[], 0.0, 100.0, 5), range(0, 0, 0.0, 0.0));
+        assert_eq!(
+            window_range(0, RowHeight::Measured(10.0), &row_offsets(10.0, &[]), 0.0, 100.0, 5),
+            range(0, 0, 0.0, 0.0)
+        );
+    }
+}
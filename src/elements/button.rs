@@ -1,7 +1,11 @@
+#![allow(non_local_definitions)]
+
 use derive_more::Display;
 use yew::events::{Event, MouseEvent};
 use yew::prelude::*;
 
+use crate::Color;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct ButtonsProps {
     #[prop_or_default]
@@ -49,6 +53,9 @@ pub struct ButtonProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<Color>,
     /// The click handler to use for this component.
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
@@ -71,6 +78,7 @@ pub fn button(props: &ButtonProps) -> Html {
     let class = classes!(
         "button",
         props.classes.clone(),
+        props.color.as_ref().map(ToString::to_string),
         props.loading.then_some("is-loading"),
         props.r#static.then_some("is-static")
     );
@@ -92,15 +100,21 @@ mod router {
     use yew_router::Routable;
 
     #[derive(Clone, Properties, PartialEq)]
-    pub struct ButtonRouterProps<R: Routable + Clone + PartialEq + 'static> {
+    pub struct ButtonRouterProps<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + 'static = ()> {
         /// The Switched item representing the route.
         pub route: R,
+        /// The query parameters appended to the route, serialized as a query string.
+        #[prop_or_default]
+        pub query: Option<Q>,
         /// Html inside the component.
         #[prop_or_default]
         pub children: Children,
         /// Classes to be added to component.
         #[prop_or_default]
         pub classes: Classes,
+        /// The color of this component.
+        #[prop_or_default]
+        pub color: Option<Color>,
         /// Render a loading spinner within this component.
         #[prop_or_default]
         pub loading: bool,
@@ -120,7 +134,7 @@ mod router {
 
     impl<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static> Component for ButtonRouter<R, Q> {
         type Message = ();
-        type Properties = ButtonRouterProps<R>;
+        type Properties = ButtonRouterProps<R, Q>;
 
         fn create(_ctx: &Context<Self>) -> Self {
             Self {
@@ -131,10 +145,12 @@ mod router {
 
         fn view(&self, ctx: &Context<Self>) -> Html {
             let loading = ctx.props().loading.then_some("is-loading");
-            let classes = classes!(ctx.props().classes.clone(), "button", loading);
+            let color = ctx.props().color.as_ref().map(ToString::to_string);
+            let classes = classes!(ctx.props().classes.clone(), "button", color, loading);
             html! {
                 <Link<R, Q>
                     to={ctx.props().route.clone()}
+                    query={ctx.props().query.clone()}
                     disabled={ctx.props().disabled}
                     {classes}
                     children={ctx.props().children.clone()}
@@ -151,7 +167,7 @@ mod router {
 
     impl<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static> Component for ButtonAnchorRouter<R, Q> {
         type Message = ();
-        type Properties = ButtonRouterProps<R>;
+        type Properties = ButtonRouterProps<R, Q>;
 
         fn create(_ctx: &Context<Self>) -> Self {
             Self {
@@ -162,10 +178,12 @@ mod router {
 
         fn view(&self, ctx: &Context<Self>) -> Html {
             let loading = ctx.props().loading.then_some("is-loading");
-            let classes = classes!(ctx.props().classes.clone(), "button", loading);
+            let color = ctx.props().color.as_ref().map(ToString::to_string);
+            let classes = classes!(ctx.props().classes.clone(), "button", color, loading);
             html! {
                 <Link<R, Q>
                     to={ctx.props().route.clone()}
+                    query={ctx.props().query.clone()}
                     disabled={ctx.props().disabled}
                     {classes}
                     children={ctx.props().children.clone()}
@@ -187,6 +205,9 @@ pub struct ButtonAnchorProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<Color>,
     /// The `href` attribute value to use for this component.
     #[prop_or_default]
     pub href: String,
@@ -218,6 +239,7 @@ pub fn button_anchor(props: &ButtonAnchorProps) -> Html {
     let class = classes!(
         "button",
         props.classes.clone(),
+        props.color.as_ref().map(ToString::to_string),
         props.loading.then_some("is-loading"),
         props.r#static.then_some("is-static")
     );
@@ -1,3 +1,5 @@
+#![allow(non_local_definitions)]
+
 use derive_more::Display;
 use yew::prelude::*;
 
@@ -1,11 +1,33 @@
+use std::time::Duration;
+
 use yew::prelude::*;
 
+use crate::{Color, Delete};
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct NotificationProps {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Render a delete button in the top right corner of this component.
+    #[prop_or_default]
+    pub dismissible: bool,
+    /// Control the visibility of this component from the parent component.
+    ///
+    /// When `None`, this component hides itself once dismissed. When `Some`, this component is
+    /// shown exactly when the value is `true`, and dismissals are only reported via `on_close`.
+    #[prop_or_default]
+    pub open: Option<bool>,
+    /// The callback invoked when this component is dismissed, after its fade-out transition if any.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// Fade this component out when dismissed.
+    #[prop_or_default]
+    pub fade: bool,
 }
 
 /// Bold notification blocks, to alert your users of something.
@@ -13,10 +35,80 @@ pub struct NotificationProps {
 /// [https://bulma.io/documentation/elements/notification/](https://bulma.io/documentation/elements/notification/)
 #[function_component(Notification)]
 pub fn notification(props: &NotificationProps) -> Html {
-    let class = classes!("notification", props.classes.clone());
+    let dismissible = use_dismissible(props.open, props.on_close.clone(), props.fade);
+    if !dismissible.visible {
+        return html! {};
+    }
+    let class = classes!("notification", props.classes.clone(), props.color.as_ref().map(ToString::to_string));
+    let delete = props.dismissible.then(|| {
+        html! {
+            <Delete onclick={dismissible.close.reform(|_| ())} />
+        }
+    });
     html! {
-        <div {class}>
+        <div {class} style={dismissible.style}>
+            {delete}
             {props.children.clone()}
         </div>
     }
 }
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// The duration of the fade-out transition of dismissible components.
+const FADE_DURATION: Duration = Duration::from_millis(300);
+
+/// The state of a dismissible component, as returned by `use_dismissible`.
+pub(crate) struct Dismissible {
+    /// Whether the component is rendered.
+    pub visible: bool,
+    /// The inline style of the component, used for its fade-out transition.
+    pub style: Option<&'static str>,
+    /// The callback used for dismissing the component.
+    pub close: Callback<()>,
+}
+
+/// The visibility state shared by dismissible components, such as `Notification` & `Message`.
+///
+/// When `open` is `Some`, the visibility is controlled by the parent and dismissals are only
+/// reported via `on_close`. Otherwise, the component hides itself once dismissed.
+#[hook]
+pub(crate) fn use_dismissible(open: Option<bool>, on_close: Callback<()>, fade: bool) -> Dismissible {
+    let hidden = use_state(|| false);
+    let fading = use_state(|| false);
+
+    let close = {
+        let (hidden, fading) = (hidden.clone(), fading.clone());
+        Callback::from(move |_| {
+            if *fading {
+                return;
+            }
+            let finish = {
+                let (hidden, fading, on_close) = (hidden.clone(), fading.clone(), on_close.clone());
+                move || {
+                    fading.set(false);
+                    if open.is_none() {
+                        hidden.set(true);
+                    }
+                    on_close.emit(());
+                }
+            };
+            if fade {
+                fading.set(true);
+                yew::platform::spawn_local(async move {
+                    yew::platform::time::sleep(FADE_DURATION).await;
+                    finish();
+                });
+            } else {
+                finish();
+            }
+        })
+    };
+    let style = match (fade, *fading) {
+        (false, _) => None,
+        (true, false) => Some("transition: opacity 300ms ease-out;"),
+        (true, true) => Some("transition: opacity 300ms ease-out; opacity: 0;"),
+    };
+
+    Dismissible { visible: open.unwrap_or(!*hidden), style, close }
+}
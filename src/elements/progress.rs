@@ -1,9 +1,14 @@
 use yew::prelude::*;
 
+use crate::Color;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct ProgressProps {
     #[prop_or_default]
     pub classes: Classes,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<Color>,
     /// The maximum amount of progress; the 100% value.
     #[prop_or_else(|| 1.0)]
     pub max: f32,
@@ -17,7 +22,7 @@ pub struct ProgressProps {
 /// [https://bulma.io/documentation/elements/progress/](https://bulma.io/documentation/elements/progress/)
 #[function_component(Progress)]
 pub fn progress(props: &ProgressProps) -> Html {
-    let class = classes!("progress", props.classes.clone());
+    let class = classes!("progress", props.classes.clone(), props.color.as_ref().map(ToString::to_string));
     let max = props.max.to_string();
     let value = props.value.to_string();
     let value_txt = format!("{}%", value);
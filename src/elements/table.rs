@@ -1,5 +1,15 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
+
+use derive_more::Display;
+use web_sys::HtmlTableElement;
 use yew::prelude::*;
 
+use crate::components::virtual_scroll::use_virtual_window;
+use crate::{Checkbox, Icon, Pagination, Size, Virtualize};
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct TableProps {
     #[prop_or_default]
@@ -24,6 +34,13 @@ pub struct TableProps {
     /// Make the table scrollable, wrapping the table in a `div.table-container`.
     #[prop_or_default]
     pub scrollable: bool,
+    /// Render the rows of the table's body via virtual scrolling, which only renders the rows
+    /// within the viewport of the table.
+    ///
+    /// The table is then scrollable, and its children are expected to be its `thead`, which is
+    /// kept visible by making it sticky once mounted.
+    #[prop_or_default]
+    pub virtualize: Option<Virtualize>,
 }
 
 /// An HTML table component.
@@ -31,6 +48,27 @@ pub struct TableProps {
 /// [https://bulma.io/documentation/elements/table/](https://bulma.io/documentation/elements/table/)
 #[function_component(Table)]
 pub fn table(props: &TableProps) -> Html {
+    let window = use_virtual_window(props.virtualize.clone());
+    let table = use_node_ref();
+    {
+        // The caller's own `thead` is made sticky, rather than wrapping the children in another one.
+        let (table, virtualized) = (table.clone(), props.virtualize.is_some());
+        use_effect_with_deps(
+            move |_| {
+                let head = table
+                    .cast::<HtmlTableElement>()
+                    .filter(|_| virtualized)
+                    .and_then(|table| table.t_head());
+                if let Some(head) = head {
+                    let style = head.style();
+                    for (property, value) in STICKY_HEAD {
+                        let _ = style.set_property(property, value);
+                    }
+                }
+            },
+            (props.children.clone(), virtualized),
+        );
+    }
     let class = classes!(
         "table",
         props.classes.clone(),
@@ -40,7 +78,21 @@ pub fn table(props: &TableProps) -> Html {
         props.hoverable.then_some("is-hoverable"),
         props.fullwidth.then_some("is-fullwidth"),
     );
-    if props.scrollable {
+    if let Some(virtualize) = &props.virtualize {
+        let rows = window.render(virtualize, |height| {
+            html! {
+                <tr aria-hidden="true"><td style={format!("height: {}px; padding: 0; border: 0;", height)}></td></tr>
+            }
+        });
+        html! {
+            <div class="table-container" style={window.style.clone()} ref={window.scroller.clone()} onscroll={window.onscroll.clone()}>
+                <table {class} ref={table}>
+                    {props.children.clone()}
+                    <tbody ref={window.items.clone()}>{rows}</tbody>
+                </table>
+            </div>
+        }
+    } else if props.scrollable {
         html! {
             <div class="table-container">
                 <table {class}>
@@ -56,3 +108,596 @@ pub fn table(props: &TableProps) -> Html {
         }
     }
 }
+
+/// The style properties keeping the `thead` of a virtualized table visible while scrolling.
+const STICKY_HEAD: [(&str, &str); 4] = [("position", "sticky"), ("top", "0"), ("z-index", "1"), ("background-color", "inherit")];
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+/// The definition of a column of a `DataTable`.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use ybc::DataTableColumn;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct User { name: String, age: u32 }
+///
+/// let columns = vec![
+///     DataTableColumn::new("name", "Name", |user: &User| html! {user.name.clone()}).sort_by_key(|user| user.name.clone()),
+///     DataTableColumn::new("age", "Age", |user: &User| html! {user.age}).sort_by_key(|user| user.age),
+/// ];
+/// ```
+pub struct DataTableColumn<T> {
+    id: String,
+    header: Html,
+    cell: Rc<dyn Fn(&T) -> Html>,
+    sortable: bool,
+    compare: Option<Compare<T>>,
+}
+
+/// The comparison function of a sortable `DataTableColumn`.
+type Compare<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+impl<T> DataTableColumn<T> {
+    /// Create a column with the given ID, header & cell renderer.
+    ///
+    /// The ID identifies this column in the `TableSort` of its table.
+    pub fn new(id: impl Into<String>, header: impl Into<Html>, cell: impl Fn(&T) -> Html + 'static) -> Self {
+        Self {
+            id: id.into(),
+            header: header.into(),
+            cell: Rc::new(cell),
+            sortable: false,
+            compare: None,
+        }
+    }
+
+    /// Make this column sortable, using the given comparison function when sorting on the client.
+    pub fn sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.sortable = true;
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Make this column sortable, using the given key function when sorting on the client.
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&T) -> K + 'static) -> Self {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Make this column sortable without a comparison function, for tables sorted on the server.
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+
+    /// The ID of this column.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl<T> Clone for DataTableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            header: self.header.clone(),
+            cell: self.cell.clone(),
+            sortable: self.sortable,
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for DataTableColumn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let compare_eq = match (&self.compare, &other.compare) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        self.id == other.id && self.header == other.header && Rc::ptr_eq(&self.cell, &other.cell) && self.sortable == other.sortable && compare_eq
+    }
+}
+
+impl<T> fmt::Debug for DataTableColumn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataTableColumn")
+            .field("id", &self.id)
+            .field("sortable", &self.sortable)
+            .finish()
+    }
+}
+
+/// The direction in which a `DataTable` is sorted.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum SortDirection {
+    #[display(fmt = "ascending")]
+    Ascending,
+    #[display(fmt = "descending")]
+    Descending,
+}
+
+/// The column by which a `DataTable` is sorted, along with the sort direction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableSort {
+    /// The ID of the sorted column.
+    pub column: String,
+    /// The direction in which the column is sorted.
+    pub direction: SortDirection,
+}
+
+impl TableSort {
+    /// The sort which results from clicking the header of the given column: ascending, unless the
+    /// column is already sorted in ascending order.
+    fn toggle(current: Option<&TableSort>, column: &str) -> TableSort {
+        let direction = match current {
+            Some(sort) if sort.column == column && sort.direction == SortDirection::Ascending => SortDirection::Descending,
+            _ => SortDirection::Ascending,
+        };
+        TableSort { column: column.to_string(), direction }
+    }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct DataTableProps<T: Clone + PartialEq + 'static> {
+    /// The rows of this table.
+    pub rows: Vec<T>,
+    /// The columns of this table.
+    pub columns: Vec<DataTableColumn<T>>,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// The content rendered in place of the rows when there are no rows.
+    #[prop_or_default]
+    pub empty: Html,
+    /// Sort & page the rows on the server rather than on the client.
+    ///
+    /// The rows are then rendered exactly as given, the `sort` & `page` props are expected to be
+    /// updated from the `on_sort` & `on_page_change` callbacks, and `total_rows` gives the number of
+    /// rows across all pages.
+    #[prop_or_default]
+    pub server_side: bool,
+    /// The sort of this table; the initial sort when sorting on the client.
+    #[prop_or_default]
+    pub sort: Option<TableSort>,
+    /// The callback invoked when the header of a sortable column is clicked.
+    #[prop_or_default]
+    pub on_sort: Callback<TableSort>,
+    /// The number of rows per page; when `None`, all rows are rendered on a single page.
+    #[prop_or_default]
+    pub page_size: Option<usize>,
+    /// The current page, starting from 1, when paging on the server.
+    #[prop_or(1)]
+    pub page: usize,
+    /// The number of rows across all pages, when paging on the server.
+    #[prop_or_default]
+    pub total_rows: usize,
+    /// The callback invoked when another page is selected.
+    #[prop_or_default]
+    pub on_page_change: Callback<usize>,
+    /// The size of the pagination of this table.
+    #[prop_or_default]
+    pub pagination_size: Option<Size>,
+    /// The callback used for computing the key of a row, which identifies the row in the
+    /// selection of this table; defaults to the index of the row within `rows`.
+    #[prop_or_default]
+    pub row_key: Option<Callback<T, String>>,
+    /// Render a checkbox for selecting each row, along with a select-all checkbox in the header.
+    #[prop_or_default]
+    pub selectable: bool,
+    /// Control the keys of the selected rows from the parent component.
+    ///
+    /// When `None`, the table manages its own selection. When `Some`, the given rows are selected,
+    /// and changes to the selection are only reported via `on_selection_change`.
+    #[prop_or_default]
+    pub selected: Option<Vec<String>>,
+    /// The callback invoked with the keys of the selected rows when the selection changes.
+    #[prop_or_default]
+    pub on_selection_change: Callback<Vec<String>>,
+    /// The callback used for rendering the detail of a row, making the rows expandable.
+    ///
+    /// Each row is then given a toggle button, which shows the detail in a row below it.
+    #[prop_or_default]
+    pub detail: Option<Callback<T, Html>>,
+    /// Add borders to all the cells.
+    #[prop_or_default]
+    pub bordered: bool,
+    /// Add stripes to the table.
+    #[prop_or_default]
+    pub striped: bool,
+    /// Make the cells narrower.
+    #[prop_or_default]
+    pub narrow: bool,
+    /// Add a hover effect on each row.
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Make the table fullwidth.
+    #[prop_or_default]
+    pub fullwidth: bool,
+    /// Make the table scrollable, wrapping the table in a `div.table-container`.
+    #[prop_or_default]
+    pub scrollable: bool,
+}
+
+/// A table rendered from a list of rows & column definitions, with sorting & paging.
+///
+/// [https://bulma.io/documentation/elements/table/](https://bulma.io/documentation/elements/table/)
+///
+/// Clicking the header of a sortable column sorts the table by that column, toggling between
+/// ascending & descending order. When `page_size` is given, the rows are paged via a `Pagination`
+/// component rendered below the table.
+///
+/// When `selectable` is set, each row is given a `Checkbox`, & selected rows are given the
+/// `is-selected` class. The select-all checkbox in the header applies to the rows of the current
+/// page, & shift-clicking a checkbox applies its new state to all rows since the previously
+/// clicked checkbox. When `detail` is given, each row may be expanded into a detail row.
+#[function_component(DataTable)]
+pub fn data_table<T: Clone + PartialEq + 'static>(props: &DataTableProps<T>) -> Html {
+    let sort_state = use_state(|| props.sort.clone());
+    let page_state = use_state(|| 1);
+    let (sort, page) = if props.server_side {
+        (props.sort.clone(), props.page)
+    } else {
+        ((*sort_state).clone(), *page_state)
+    };
+
+    let on_sort = {
+        let (sort_state, page_state, on_sort, server_side) = (sort_state.clone(), page_state.clone(), props.on_sort.clone(), props.server_side);
+        Callback::from(move |sort: TableSort| {
+            if !server_side {
+                sort_state.set(Some(sort.clone()));
+                page_state.set(1);
+            }
+            on_sort.emit(sort);
+        })
+    };
+    let on_page_change = {
+        let (page_state, on_page_change, server_side) = (page_state.clone(), props.on_page_change.clone(), props.server_side);
+        Callback::from(move |page: usize| {
+            if !server_side {
+                page_state.set(page);
+            }
+            on_page_change.emit(page);
+        })
+    };
+
+    let total_rows = if props.server_side { props.total_rows } else { props.rows.len() };
+    let page = clamp_page(page, total_rows, props.page_size);
+    let rows = if props.server_side {
+        props.rows.iter().enumerate().collect::<Vec<_>>()
+    } else {
+        sorted_page(&props.rows, &props.columns, sort.as_ref(), page, props.page_size)
+    };
+    let rows: Vec<(String, &T)> = rows
+        .into_iter()
+        .map(|(idx, row)| {
+            let key = props
+                .row_key
+                .as_ref()
+                .map(|row_key| row_key.emit(row.clone()))
+                .unwrap_or_else(|| idx.to_string());
+            (key, row)
+        })
+        .collect();
+    let keys: Rc<Vec<String>> = Rc::new(rows.iter().map(|(key, _)| key.clone()).collect());
+
+    let selected_state = use_state(|| props.selected.clone().unwrap_or_default());
+    let selected = props.selected.clone().unwrap_or_else(|| (*selected_state).clone());
+    let anchor = use_mut_ref(|| None::<String>);
+    let expanded = use_state(Vec::<String>::new);
+
+    let set_selected = {
+        let (selected_state, on_selection_change, controlled) = (selected_state.clone(), props.on_selection_change.clone(), props.selected.is_some());
+        Callback::from(move |selected: Vec<String>| {
+            if !controlled {
+                selected_state.set(selected.clone());
+            }
+            on_selection_change.emit(selected);
+        })
+    };
+
+    let mut headers: Vec<Html> = Vec::new();
+    if props.detail.is_some() {
+        headers.push(html! { <th></th> });
+    }
+    if props.selectable {
+        let count = keys.iter().filter(|key| selected.contains(key)).count();
+        let update = {
+            let (set_selected, selected, keys) = (set_selected.clone(), selected.clone(), keys.clone());
+            Callback::from(move |checked| set_selected.emit(select_all(&selected, &keys, checked)))
+        };
+        headers.push(html! {
+            <th>
+                <Checkbox
+                    name="select-all"
+                    checked={count > 0 && count == keys.len()}
+                    indeterminate={count > 0 && count < keys.len()}
+                    {update}
+                />
+            </th>
+        });
+    }
+    headers.extend(props.columns.iter().map(|column| {
+        if !column.sortable {
+            return html! { <th>{column.header.clone()}</th> };
+        }
+        let direction = sort
+            .as_ref()
+            .filter(|sort| sort.column == column.id)
+            .map(|sort| sort.direction);
+        let onclick = {
+            let (on_sort, sort, id) = (on_sort.clone(), sort.clone(), column.id.clone());
+            Callback::from(move |_| on_sort.emit(TableSort::toggle(sort.as_ref(), &id)))
+        };
+        let arrow = match direction {
+            Some(SortDirection::Ascending) => "▲",
+            Some(SortDirection::Descending) => "▼",
+            None => "",
+        };
+        html! {
+            <th aria-sort={direction.map(|direction| direction.to_string()).unwrap_or_else(|| "none".into())}>
+                <a role="button" {onclick}>
+                    {column.header.clone()}
+                    <Icon size={Size::Small}>{arrow}</Icon>
+                </a>
+            </th>
+        }
+    }));
+    let colspan = headers.len().to_string();
+
+    let body = if rows.is_empty() {
+        html! {
+            <tr><td {colspan}>{props.empty.clone()}</td></tr>
+        }
+    } else {
+        rows.into_iter()
+            .map(|(key, row)| {
+                let is_selected = selected.contains(&key);
+                let is_expanded = expanded.contains(&key);
+                let toggle = props.detail.as_ref().map(|_| {
+                    let onclick = {
+                        let (expanded, key) = (expanded.clone(), key.clone());
+                        Callback::from(move |_| {
+                            let mut keys = (*expanded).clone();
+                            match keys.iter().position(|expanded| *expanded == key) {
+                                Some(idx) => drop(keys.remove(idx)),
+                                None => keys.push(key.clone()),
+                            }
+                            expanded.set(keys);
+                        })
+                    };
+                    html! {
+                        <td>
+                            <a role="button" aria-expanded={is_expanded.to_string()} aria-label="Toggle details" {onclick}>
+                                <Icon size={Size::Small}>{if is_expanded { "▼" } else { "▶" }}</Icon>
+                            </a>
+                        </td>
+                    }
+                });
+                let checkbox = props.selectable.then(|| {
+                    let shift = Rc::new(Cell::new(false));
+                    let onclick = {
+                        let shift = shift.clone();
+                        Callback::from(move |event: MouseEvent| shift.set(event.shift_key()))
+                    };
+                    let update = {
+                        let (set_selected, selected, keys, anchor, key) =
+                            (set_selected.clone(), selected.clone(), keys.clone(), anchor.clone(), key.clone());
+                        Callback::from(move |checked| {
+                            let range_from = if shift.get() { anchor.borrow().clone() } else { None };
+                            set_selected.emit(select(&selected, &keys, &key, checked, range_from.as_deref()));
+                            *anchor.borrow_mut() = Some(key.clone());
+                        })
+                    };
+                    html! {
+                        <td><Checkbox name={format!("select-{}", key)} checked={is_selected} {onclick} {update} /></td>
+                    }
+                });
+                let detail = props.detail.as_ref().filter(|_| is_expanded).map(|detail| {
+                    html! {
+                        <tr class="is-detail"><td colspan={colspan.clone()}>{detail.emit(row.clone())}</td></tr>
+                    }
+                });
+                html! {
+                    <>
+                    <tr class={classes!(is_selected.then_some("is-selected"))}>
+                        {toggle}
+                        {checkbox}
+                        {for props.columns.iter().map(|column| html! { <td>{(column.cell)(row)}</td> })}
+                    </tr>
+                    {detail}
+                    </>
+                }
+            })
+            .collect()
+    };
+
+    let pagination = props.page_size.map(|page_size| {
+        html! {
+            <Pagination
+                current={page}
+                total_pages={Some(total_pages(total_rows, page_size))}
+                size={props.pagination_size.clone()}
+                {on_page_change}
+            />
+        }
+    });
+
+    html! {
+        <>
+        <Table
+            classes={props.classes.clone()}
+            bordered={props.bordered}
+            striped={props.striped}
+            narrow={props.narrow}
+            hoverable={props.hoverable}
+            fullwidth={props.fullwidth}
+            scrollable={props.scrollable}
+        >
+            <thead><tr>{headers}</tr></thead>
+            <tbody>{body}</tbody>
+        </Table>
+        {pagination}
+        </>
+    }
+}
+
+/// The number of pages needed for the given number of rows, which is at least 1.
+fn total_pages(total_rows: usize, page_size: usize) -> usize {
+    total_rows.div_ceil(page_size.max(1)).max(1)
+}
+
+/// The given page clamped to the pages available, so that the rendered rows & the pagination
+/// agree when the rows shrink.
+fn clamp_page(page: usize, total_rows: usize, page_size: Option<usize>) -> usize {
+    page_size.map_or(1, |page_size| page.clamp(1, total_pages(total_rows, page_size)))
+}
+
+/// The rows of the given page along with their indices, after sorting them on the client.
+fn sorted_page<'a, T>(
+    rows: &'a [T], columns: &[DataTableColumn<T>], sort: Option<&TableSort>, page: usize, page_size: Option<usize>,
+) -> Vec<(usize, &'a T)> {
+    let mut rows: Vec<(usize, &T)> = rows.iter().enumerate().collect();
+    let compare = sort.and_then(|sort| {
+        let column = columns.iter().find(|column| column.id == sort.column)?;
+        Some((column.compare.clone()?, sort.direction))
+    });
+    if let Some((compare, direction)) = compare {
+        rows.sort_by(|(_, a), (_, b)| match direction {
+            SortDirection::Ascending => compare(a, b),
+            SortDirection::Descending => compare(b, a),
+        });
+    }
+    match page_size {
+        Some(page_size) => rows
+            .into_iter()
+            .skip(page.saturating_sub(1) * page_size)
+            .take(page_size)
+            .collect(),
+        None => rows,
+    }
+}
+
+/// The selection resulting from setting the given row to `checked`.
+///
+/// When `range_from` is given, the state is applied to all visible rows between it & the given row.
+fn select(selected: &[String], visible: &[String], key: &str, checked: bool, range_from: Option<&str>) -> Vec<String> {
+    let position = |key: &str| visible.iter().position(|visible| visible == key);
+    let keys: Vec<&str> = match (range_from.and_then(position), position(key)) {
+        (Some(from), Some(to)) => visible[from.min(to)..=from.max(to)].iter().map(String::as_str).collect(),
+        _ => vec![key],
+    };
+    set_selected(selected, &keys, checked)
+}
+
+/// The selection resulting from setting all visible rows to `checked`.
+fn select_all(selected: &[String], visible: &[String], checked: bool) -> Vec<String> {
+    let keys: Vec<&str> = visible.iter().map(String::as_str).collect();
+    set_selected(selected, &keys, checked)
+}
+
+/// The selection resulting from setting the given rows to `checked`, keeping the selection order.
+fn set_selected(selected: &[String], keys: &[&str], checked: bool) -> Vec<String> {
+    let mut selected: Vec<String> = selected
+        .iter()
+        .filter(|key| checked || !keys.contains(&key.as_str()))
+        .cloned()
+        .collect();
+    if checked {
+        for key in keys {
+            if !selected.iter().any(|selected| selected == key) {
+                selected.push(key.to_string());
+            }
+        }
+    }
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns() -> Vec<DataTableColumn<(u32, &'static str)>> {
+        vec![
+            DataTableColumn::new("id", "ID", |row: &(u32, &str)| html! {row.0}).sort_by_key(|row| row.0),
+            DataTableColumn::new("name", "Name", |row: &(u32, &str)| html! {row.1}).sortable(),
+        ]
+    }
+
+    fn sort(column: &str, direction: SortDirection) -> TableSort {
+        TableSort { column: column.into(), direction }
+    }
+
+    fn indices<T>(rows: Vec<(usize, &T)>) -> Vec<usize> {
+        rows.into_iter().map(|(idx, _)| idx).collect()
+    }
+
+    #[test]
+    fn sorted_page_sorts_by_column() {
+        let rows = vec![(2, "b"), (3, "c"), (1, "a")];
+        let sorted = sorted_page(&rows, &columns(), Some(&sort("id", SortDirection::Ascending)), 1, None);
+        assert_eq!(indices(sorted), vec![2, 0, 1]);
+        let sorted = sorted_page(&rows, &columns(), Some(&sort("id", SortDirection::Descending)), 1, None);
+        assert_eq!(indices(sorted), vec![1, 0, 2]);
+    }
+
+    #[test]
+    fn sorted_page_keeps_order_without_comparison() {
+        let rows = vec![(2, "b"), (3, "c"), (1, "a")];
+        let sorted = sorted_page(&rows, &columns(), Some(&sort("name", SortDirection::Ascending)), 1, None);
+        assert_eq!(indices(sorted), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn sorted_page_pages_rows() {
+        let rows: Vec<_> = (1..=5).map(|id| (id, "row")).collect();
+        assert_eq!(indices(sorted_page(&rows, &columns(), None, 2, Some(2))), vec![2, 3]);
+        assert_eq!(indices(sorted_page(&rows, &columns(), None, 3, Some(2))), vec![4]);
+    }
+
+    #[test]
+    fn sorted_page_clamps_the_page_when_rows_shrink() {
+        let rows: Vec<_> = (1..=2).map(|id| (id, "row")).collect();
+        let page = clamp_page(3, rows.len(), Some(2));
+        assert_eq!(page, 1);
+        assert_eq!(indices(sorted_page(&rows, &columns(), None, page, Some(2))), vec![0, 1]);
+        assert_eq!(clamp_page(3, 0, Some(2)), 1);
+        assert_eq!(clamp_page(3, 5, None), 1);
+    }
+
+    fn keys(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn select_toggles_a_single_row() {
+        let visible = keys(&["a", "b", "c"]);
+        assert_eq!(select(&keys(&["x"]), &visible, "b", true, None), keys(&["x", "b"]));
+        assert_eq!(select(&keys(&["x", "b"]), &visible, "b", false, None), keys(&["x"]));
+    }
+
+    #[test]
+    fn select_applies_to_a_range() {
+        let visible = keys(&["a", "b", "c", "d"]);
+        assert_eq!(select(&keys(&["a"]), &visible, "c", true, Some("a")), keys(&["a", "b", "c"]));
+        assert_eq!(select(&keys(&["a", "b", "c", "d"]), &visible, "b", false, Some("d")), keys(&["a"]));
+        assert_eq!(select(&[], &visible, "b", true, Some("gone")), keys(&["b"]));
+    }
+
+    #[test]
+    fn select_all_applies_to_visible_rows() {
+        let visible = keys(&["a", "b"]);
+        assert_eq!(select_all(&keys(&["x", "a"]), &visible, true), keys(&["x", "a", "b"]));
+        assert_eq!(select_all(&keys(&["x", "a"]), &visible, false), keys(&["x"]));
+    }
+
+    #[test]
+    fn table_sort_toggles_direction() {
+        let ascending = sort("id", SortDirection::Ascending);
+        assert_eq!(TableSort::toggle(None, "id"), ascending);
+        assert_eq!(TableSort::toggle(Some(&ascending), "id"), sort("id", SortDirection::Descending));
+        assert_eq!(TableSort::toggle(Some(&sort("id", SortDirection::Descending)), "id"), ascending);
+        assert_eq!(TableSort::toggle(Some(&ascending), "name"), sort("name", SortDirection::Ascending));
+    }
+}
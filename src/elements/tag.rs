@@ -1,6 +1,6 @@
 use yew::prelude::*;
 
-use crate::Size;
+use crate::{Color, Size};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct TagProps {
@@ -8,6 +8,9 @@ pub struct TagProps {
     pub children: Children,
     #[prop_or_default]
     pub classes: Classes,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<Color>,
     /// The HTML tag to use for this component.
     #[prop_or_else(|| "span".into())]
     pub tag: String,
@@ -33,6 +36,7 @@ pub fn tag(props: &TagProps) -> Html {
     let class = classes!(
         "tag",
         props.classes.clone(),
+        props.color.as_ref().map(ToString::to_string),
         props.rounded.then_some("is-rounded"),
         props.delete.then_some("is-delete"),
         props.size.as_ref().map(|size| size.to_string()),
@@ -44,6 +48,83 @@ pub fn tag(props: &TagProps) -> Html {
     }
 }
 
+#[cfg(feature = "router")]
+mod router {
+    use super::*;
+    use serde::Serialize;
+    use yew_router::components::Link;
+    use yew_router::Routable;
+
+    #[derive(Clone, Properties, PartialEq)]
+    pub struct TagRouterProps<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + 'static = ()> {
+        /// The Switched item representing the route.
+        pub route: R,
+        /// The query parameters appended to the route, serialized as a query string.
+        #[prop_or_default]
+        pub query: Option<Q>,
+        /// Html inside the component.
+        #[prop_or_default]
+        pub children: Children,
+        #[prop_or_default]
+        pub classes: Classes,
+        /// The color of this component.
+        #[prop_or_default]
+        pub color: Option<Color>,
+        /// Make this tag rounded.
+        #[prop_or_default]
+        pub rounded: bool,
+        /// Turn this tag into a delete button.
+        #[prop_or_default]
+        pub delete: bool,
+        /// The size for this component.
+        #[prop_or_default]
+        pub size: Option<Size>,
+    }
+
+    /// A Yew Router link rendered as a small tag label.
+    ///
+    /// [https://bulma.io/documentation/elements/tag/](https://bulma.io/documentation/elements/tag/)
+    pub struct TagRouter<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static = ()> {
+        _route: std::marker::PhantomData<R>,
+        _query: std::marker::PhantomData<Q>,
+    }
+
+    impl<R: Routable + Clone + PartialEq + 'static, Q: Clone + PartialEq + Serialize + 'static> Component for TagRouter<R, Q> {
+        type Message = ();
+        type Properties = TagRouterProps<R, Q>;
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self {
+                _route: std::marker::PhantomData,
+                _query: std::marker::PhantomData,
+            }
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            let props = ctx.props();
+            let classes = classes!(
+                "tag",
+                props.classes.clone(),
+                props.color.as_ref().map(ToString::to_string),
+                props.rounded.then_some("is-rounded"),
+                props.delete.then_some("is-delete"),
+                props.size.as_ref().map(|size| size.to_string()),
+            );
+            html! {
+                <Link<R, Q>
+                    to={props.route.clone()}
+                    query={props.query.clone()}
+                    {classes}
+                    children={props.children.clone()}
+                />
+            }
+        }
+    }
+}
+
+#[cfg(feature = "router")]
+pub use router::{TagRouter, TagRouterProps};
+
 //////////////////////////////////////////////////////////////////////////////
 //////////////////////////////////////////////////////////////////////////////
 
@@ -1,5 +1,8 @@
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
+use crate::form::validation::use_invalid;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct CheckboxProps {
     /// The `name` attribute for this form element.
@@ -15,6 +18,16 @@ pub struct CheckboxProps {
     /// Disable this component.
     #[prop_or_default]
     pub disabled: bool,
+    /// Render this checkbox in the indeterminate state, e.g. for a partial selection.
+    #[prop_or_default]
+    pub indeterminate: bool,
+    /// The click handler of the checkbox, invoked before `update`; useful for inspecting modifier
+    /// keys of the click.
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
+    /// The callback invoked when this element loses focus.
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
 }
 
 /// The 2-state checkbox in its native format.
@@ -26,15 +39,40 @@ pub struct CheckboxProps {
 /// component via callback.
 #[function_component(Checkbox)]
 pub fn checkbox(props: &CheckboxProps) -> Html {
+    let invalid = use_invalid(&props.name);
     let class = classes!("checkbox", props.classes.clone());
+    let node = use_node_ref();
+    {
+        // The indeterminate state is only available as a DOM property, which the browser clears on
+        // click, so it is re-applied whenever the checked state changes as well.
+        let node = node.clone();
+        use_effect_with_deps(
+            move |&(indeterminate, _)| {
+                if let Some(input) = node.cast::<HtmlInputElement>() {
+                    input.set_indeterminate(indeterminate);
+                }
+            },
+            (props.indeterminate, props.checked),
+        );
+    }
     let checked = props.checked;
+    let onclick = {
+        let (onclick, update) = (props.onclick.clone(), props.update.clone());
+        Callback::from(move |event: MouseEvent| {
+            onclick.emit(event);
+            update.emit(!checked);
+        })
+    };
     html! {
         <label {class} disabled={props.disabled}>
             <input
                 type="checkbox"
+                ref={node}
                 checked={props.checked}
                 name={props.name.clone()}
-                onclick={props.update.reform(move |_| !checked)}
+                onblur={props.onblur.clone()}
+                aria-invalid={invalid.then_some("true")}
+                {onclick}
                 disabled={props.disabled}
                 />
             {props.children.clone()}
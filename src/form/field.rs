@@ -1,6 +1,10 @@
+#![allow(non_local_definitions)]
+
 use derive_more::Display;
 use yew::prelude::*;
 
+use crate::ValidationErrors;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct FieldProps {
     #[prop_or_default]
@@ -22,6 +26,10 @@ pub struct FieldProps {
     /// A convenience bool to add the `is-danger` class to the help classes when `true`.
     #[prop_or_default]
     pub help_has_error: bool,
+    /// The `name` of the form control of this field. Within a `Form`, the validation messages of
+    /// that control are rendered in place of the help message, with the `is-danger` class.
+    #[prop_or_default]
+    pub name: Option<String>,
     /// Has icons on the left of the field's controls.
     #[prop_or_default]
     pub icons_left: bool,
@@ -51,6 +59,7 @@ pub struct FieldProps {
 /// A container for form controls
 #[function_component(Field)]
 pub fn field(props: &FieldProps) -> Html {
+    let errors = use_context::<ValidationErrors>();
     let class = classes!(
         "field",
         props.classes.clone(),
@@ -90,8 +99,16 @@ pub fn field(props: &FieldProps) -> Html {
         None => html! {},
     };
 
-    // Build the help label if present.
+    // Build the help label if present, or the validation messages of the field if any.
+    let messages = match (&errors, &props.name) {
+        (Some(errors), Some(name)) => errors.field(name),
+        _ => &[],
+    };
     let help = match &props.help {
+        _ if !messages.is_empty() => {
+            let class = classes!("help", props.help_classes.clone(), "is-danger");
+            html! {{for messages.iter().map(|message| html! {<label class={class.clone()}>{message.clone()}</label>})}}
+        }
         Some(help_content) => {
             if props.help_classes.is_empty() {
                 let class = classes!("help", props.help_has_error.then_some("is-danger"));
@@ -2,7 +2,8 @@ use wasm_bindgen::UnwrapThrowExt;
 use web_sys::{File as SysFile, HtmlInputElement};
 use yew::prelude::*;
 
-use crate::{Alignment, Size};
+use crate::form::validation::use_invalid;
+use crate::{Alignment, Color, Size};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct FileProps {
@@ -42,9 +43,15 @@ pub struct FileProps {
     /// The size of this component.
     #[prop_or_default]
     pub size: Option<Size>,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<Color>,
     /// The alignment of this component within its parent.
     #[prop_or_default]
     pub alignment: Option<Alignment>,
+    /// The callback invoked when this element loses focus.
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
 }
 
 /// A custom file upload input.
@@ -56,28 +63,38 @@ pub struct FileProps {
 /// component via callback.
 #[function_component(File)]
 pub fn file(props: &FileProps) -> Html {
+    let invalid = use_invalid(&props.name);
     let class = classes!(
         "file",
         props.classes.clone(),
+        invalid.then_some("is-danger"),
         props.has_name.is_some().then_some("has-name"),
         props.right.then_some("is-right"),
         props.fullwidth.then_some("is-fullwidth"),
         props.boxed.then_some("is-boxed"),
         props.size.as_ref().map(|size| size.to_string()),
+        props.color.as_ref().map(|color| color.to_string()),
         props.alignment.as_ref().map(|alignment| alignment.to_string()),
     );
-    let filenames = props
+    // Files only exist within the browser, so when the `ssr` feature is enabled for server-side
+    // rendering, only the placeholder name is rendered.
+    #[cfg(not(feature = "ssr"))]
+    let mut filenames = props
         .files
         .iter()
         .map(|file| html! {<span class="file-name">{file.name()}</span>})
         .collect::<Vec<_>>();
+    #[cfg(feature = "ssr")]
+    let mut filenames = Vec::new();
+    if filenames.is_empty() {
+        if let Some(placeholder) = &props.has_name {
+            filenames.push(html! {<span class="file-name">{placeholder.clone()}</span>});
+        }
+    }
     let onchange = props.update.reform(|ev: web_sys::Event| {
         let input: HtmlInputElement = ev.target_dyn_into().expect_throw("event target should be an input");
         let list = input.files().expect_throw("input should have a file list");
-        (0..list.length())
-            .into_iter()
-            .filter_map(|idx| list.item(idx))
-            .collect::<Vec<_>>()
+        (0..list.length()).filter_map(|idx| list.item(idx)).collect::<Vec<_>>()
     });
     html! {
         <div {class}>
@@ -86,6 +103,8 @@ pub fn file(props: &FileProps) -> Html {
                     type="file"
                     class="file-input"
                     name={props.name.clone()}
+                    onblur={props.onblur.clone()}
+                    aria-invalid={invalid.then_some("true")}
                     multiple={props.multiple}
                     {onchange}
                     />
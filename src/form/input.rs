@@ -3,7 +3,8 @@ use wasm_bindgen::UnwrapThrowExt;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
-use crate::Size;
+use crate::form::validation::use_invalid;
+use crate::{Color, Size};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct InputProps {
@@ -25,6 +26,9 @@ pub struct InputProps {
     /// The size of this component.
     #[prop_or_default]
     pub size: Option<Size>,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<Color>,
     /// Use rounded appearance.
     #[prop_or_default]
     pub rounded: bool,
@@ -40,6 +44,9 @@ pub struct InputProps {
     /// Make this component static.
     #[prop_or_default]
     pub r#static: bool,
+    /// The callback invoked when this element loses focus.
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
 }
 
 /// A text input element.
@@ -51,10 +58,13 @@ pub struct InputProps {
 /// component via callback.
 #[function_component(Input)]
 pub fn input(props: &InputProps) -> Html {
+    let invalid = use_invalid(&props.name);
     let class = classes!(
         "input",
         props.classes.clone(),
+        invalid.then_some("is-danger"),
         props.size.as_ref().map(|size| size.to_string()),
+        props.color.as_ref().map(|color| color.to_string()),
         props.rounded.then_some("is-rounded"),
         props.loading.then_some("is-loading"),
         props.r#static.then_some("is-static"),
@@ -66,6 +76,8 @@ pub fn input(props: &InputProps) -> Html {
     html! {
         <input
             name={props.name.clone()}
+            onblur={props.onblur.clone()}
+            aria-invalid={invalid.then_some("true")}
             value={props.value.clone()}
             {oninput}
             {class}
@@ -5,4 +5,6 @@ pub mod file;
 pub mod input;
 pub mod radio;
 pub mod select;
+pub mod state;
 pub mod textarea;
+pub mod validation;
@@ -2,6 +2,8 @@ use wasm_bindgen::UnwrapThrowExt;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
+use crate::form::validation::use_invalid;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct RadioProps {
     /// The `name` attribute for this form element.
@@ -24,6 +26,9 @@ pub struct RadioProps {
     /// Disable this component.
     #[prop_or_default]
     pub disabled: bool,
+    /// The callback invoked when this element loses focus.
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
 }
 
 /// The mutually exclusive radio buttons in their native format.
@@ -35,6 +40,7 @@ pub struct RadioProps {
 /// component via callback.
 #[function_component(Radio)]
 pub fn radio(props: &RadioProps) -> Html {
+    let invalid = use_invalid(&props.name);
     let class = classes!("radio", props.classes.clone());
     let oninput = props.update.reform(|ev: web_sys::InputEvent| {
         let input: HtmlInputElement = ev.target_dyn_into().expect_throw("event target should be an input");
@@ -45,6 +51,8 @@ pub fn radio(props: &RadioProps) -> Html {
             <input
                 type="radio"
                 name={props.name.clone()}
+                onblur={props.onblur.clone()}
+                aria-invalid={invalid.then_some("true")}
                 value={props.value.clone()}
                 checked={props.checked_value.as_ref().map(|val| val == &props.value).unwrap_or(false)}
                 {oninput}
@@ -2,7 +2,8 @@ use wasm_bindgen::UnwrapThrowExt;
 use web_sys::HtmlSelectElement;
 use yew::prelude::*;
 
-use crate::Size;
+use crate::form::validation::use_invalid;
+use crate::{Color, Size};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct SelectProps {
@@ -22,12 +23,18 @@ pub struct SelectProps {
     /// The size of this component.
     #[prop_or_default]
     pub size: Option<Size>,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<Color>,
     /// Display a loading spinner within this component.
     #[prop_or_default]
     pub loading: bool,
     /// Disable this component.
     #[prop_or_default]
     pub disabled: bool,
+    /// The callback invoked when this element loses focus.
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
 }
 
 /// A wrapper around an HTML `select` tag.
@@ -43,10 +50,13 @@ pub struct SelectProps {
 /// option element also has the `selected=true` attribute.
 #[function_component(Select)]
 pub fn select(props: &SelectProps) -> Html {
+    let invalid = use_invalid(&props.name);
     let class = classes!(
         "select",
         props.classes.clone(),
+        invalid.then_some("is-danger"),
         props.size.as_ref().map(|size| size.to_string()),
+        props.color.as_ref().map(|color| color.to_string()),
         props.loading.then_some("is-loading"),
     );
     let onchange = props.update.reform(|ev: web_sys::Event| {
@@ -57,6 +67,8 @@ pub fn select(props: &SelectProps) -> Html {
         <div {class}>
             <select
                 name={props.name.clone()}
+                onblur={props.onblur.clone()}
+                aria-invalid={invalid.then_some("true")}
                 value={props.value.clone()}
                 disabled={props.disabled}
                 {onchange}
@@ -88,6 +100,9 @@ pub struct MultiSelectProps {
     /// The size of this component.
     #[prop_or_default]
     pub size: Option<Size>,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<Color>,
     /// Size of the list to display.
     #[prop_or_else(|| 4)]
     pub list_size: u32,
@@ -97,6 +112,9 @@ pub struct MultiSelectProps {
     /// Disable this component.
     #[prop_or_default]
     pub disabled: bool,
+    /// The callback invoked when this element loses focus.
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
 }
 
 /// A wrapper around an HTML `select` tag with the `multiple=true` attribute.
@@ -112,11 +130,14 @@ pub struct MultiSelectProps {
 /// option element also has the `selected=true` attribute.
 #[function_component(MultiSelect)]
 pub fn multi_select(props: &MultiSelectProps) -> Html {
+    let invalid = use_invalid(&props.name);
     let class = classes!(
         "select",
         "is-multiple",
         props.classes.clone(),
+        invalid.then_some("is-danger"),
         props.size.as_ref().map(|size| size.to_string()),
+        props.color.as_ref().map(|color| color.to_string()),
         props.loading.then_some("is-loading"),
     );
     let size = props.list_size.to_string();
@@ -124,7 +145,6 @@ pub fn multi_select(props: &MultiSelectProps) -> Html {
         let select: HtmlSelectElement = ev.target_dyn_into().expect_throw("event target should be a select");
         let opts = select.selected_options();
         (0..opts.length())
-            .into_iter()
             .filter_map(|idx| opts.item(idx))
             .filter_map(|elem| elem.get_attribute("value").or_else(|| elem.text_content()))
             .collect::<Vec<_>>()
@@ -135,6 +155,8 @@ pub fn multi_select(props: &MultiSelectProps) -> Html {
                 multiple=true
                 size={size}
                 name={props.name.clone()}
+                onblur={props.onblur.clone()}
+                aria-invalid={invalid.then_some("true")}
                 value={props.value.join(",")}
                 disabled={props.disabled}
                 {onchange}
@@ -0,0 +1,398 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+/// A field of the form struct `T` holding a value of type `V`, as used by `use_form`.
+///
+/// Fields are typically declared once as constants:
+///
+/// ```rust
+/// use ybc::FormField;
+///
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Signup {
+///     email: String,
+///     terms: bool,
+/// }
+///
+/// const EMAIL: FormField<Signup, String> = FormField::new("email", |form| &form.email, |form| &mut form.email);
+/// const TERMS: FormField<Signup, bool> = FormField::new("terms", |form| &form.terms, |form| &mut form.terms);
+/// ```
+pub struct FormField<T, V> {
+    name: &'static str,
+    get: fn(&T) -> &V,
+    get_mut: fn(&mut T) -> &mut V,
+}
+
+impl<T, V> FormField<T, V> {
+    /// A field with the given `name` attribute, accessed via the given accessors.
+    pub const fn new(name: &'static str, get: fn(&T) -> &V, get_mut: fn(&mut T) -> &mut V) -> Self {
+        Self { name, get, get_mut }
+    }
+
+    /// The `name` attribute of this field.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The value of this field within the given form.
+    pub(crate) fn value<'a>(&self, form: &'a T) -> &'a V {
+        (self.get)(form)
+    }
+}
+
+impl<T, V> Clone for FormField<T, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, V> Copy for FormField<T, V> {}
+
+/// The props binding a form control to a field of a form, as returned by `UseFormHandle::bind`.
+///
+/// The `value` is passed as the `value` prop of `Input`, `TextArea`, `Select` & `MultiSelect`, as
+/// the `files` prop of `File`, as the `checked` prop of `Checkbox` & as the `checked_value` prop
+/// of `Radio`.
+///
+/// ```rust
+/// use web_sys::File as SysFile;
+/// use yew::prelude::*;
+/// use ybc::{use_form, File, FormField, Radio};
+///
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Upload {
+///     files: Vec<SysFile>,
+///     visibility: Option<String>,
+/// }
+///
+/// const FILES: FormField<Upload, Vec<SysFile>> = FormField::new("files", |form| &form.files, |form| &mut form.files);
+/// const VISIBILITY: FormField<Upload, Option<String>> =
+///     FormField::new("visibility", |form| &form.visibility, |form| &mut form.visibility);
+///
+/// #[function_component(UploadForm)]
+/// fn upload_form() -> Html {
+///     let form = use_form::<Upload>();
+///     let files = form.bind(FILES);
+///     let radio = |value: &str, label: &str| {
+///         let visibility = form.bind_radio(VISIBILITY);
+///         html! {
+///             <Radio
+///                 name={visibility.name}
+///                 value={value.to_string()}
+///                 checked_value={visibility.value}
+///                 update={visibility.update}
+///                 onblur={visibility.onblur}
+///             >
+///                 {label.to_string()}
+///             </Radio>
+///         }
+///     };
+///     html! {
+///         <form>
+///             <File name={files.name} files={files.value} update={files.update} onblur={files.onblur} />
+///             {radio("public", "Public")}
+///             {radio("private", "Private")}
+///         </form>
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormBinding<V, U = V> {
+    /// The `name` attribute of the control.
+    pub name: String,
+    /// The current value of the field.
+    pub value: V,
+    /// The callback updating the field.
+    pub update: Callback<U>,
+    /// The callback marking the field as touched.
+    pub onblur: Callback<FocusEvent>,
+}
+
+/// The state of a form, as held by `use_form`.
+#[derive(Clone, Debug, PartialEq)]
+struct FormState<T> {
+    /// The value the form is reset to.
+    initial: Rc<T>,
+    /// The current value of the form.
+    value: Rc<T>,
+    /// The names of the fields which have lost focus.
+    touched: HashSet<&'static str>,
+    /// The names of the fields which have been updated via their binding or `UseFormHandle::set`.
+    changed: HashSet<&'static str>,
+    /// Whether the form has been submitted.
+    submitted: bool,
+}
+
+enum FormAction<T> {
+    Update(Option<&'static str>, Box<dyn FnOnce(&mut T)>),
+    Touch(&'static str),
+    Submit,
+    Reset(Option<T>),
+}
+
+impl<T: Clone> Reducible for FormState<T> {
+    type Action = FormAction<T>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            FormAction::Update(name, update) => {
+                update(Rc::make_mut(&mut state.value));
+                state.changed.extend(name);
+            }
+            FormAction::Touch(name) if self.touched.contains(name) => return self,
+            FormAction::Touch(name) => {
+                state.touched.insert(name);
+            }
+            FormAction::Submit => state.submitted = true,
+            FormAction::Reset(initial) => {
+                if let Some(initial) = initial {
+                    state.initial = Rc::new(initial);
+                }
+                state.value = state.initial.clone();
+                state.touched.clear();
+                state.changed.clear();
+                state.submitted = false;
+            }
+        }
+        Rc::new(state)
+    }
+}
+
+/// A handle to the state of a form, as returned by `use_form`.
+#[derive(Clone, PartialEq)]
+pub struct UseFormHandle<T: Clone + PartialEq + 'static> {
+    state: UseReducerHandle<FormState<T>>,
+}
+
+impl<T: Clone + PartialEq + 'static> UseFormHandle<T> {
+    /// The current value of the form.
+    pub fn value(&self) -> Rc<T> {
+        self.state.value.clone()
+    }
+
+    /// The current value of the given field.
+    pub fn get<V>(&self, field: FormField<T, V>) -> &V {
+        (field.get)(&self.state.value)
+    }
+
+    /// Set the value of the given field.
+    pub fn set<V: 'static>(&self, field: FormField<T, V>, value: V) {
+        let update = move |form: &mut T| *(field.get_mut)(form) = value;
+        self.state.dispatch(FormAction::Update(Some(field.name), Box::new(update)));
+    }
+
+    /// Update the value of the form.
+    pub fn update(&self, update: impl FnOnce(&mut T) + 'static) {
+        self.state.dispatch(FormAction::Update(None, Box::new(update)));
+    }
+
+    /// The props binding a form control to the given field, i.e. `Input`, `TextArea`, `Select`,
+    /// `MultiSelect`, `Checkbox` or `File`.
+    pub fn bind<V: Clone + 'static>(&self, field: FormField<T, V>) -> FormBinding<V> {
+        let state = self.state.clone();
+        self.binding(
+            field,
+            Callback::from(move |value: V| {
+                state.dispatch(FormAction::Update(Some(field.name), Box::new(move |form| *(field.get_mut)(form) = value)));
+            }),
+        )
+    }
+
+    /// The props binding the `Radio` controls of a radio group to the given field.
+    pub fn bind_radio(&self, field: FormField<T, Option<String>>) -> FormBinding<Option<String>, String> {
+        let state = self.state.clone();
+        self.binding(
+            field,
+            Callback::from(move |value: String| {
+                state.dispatch(FormAction::Update(
+                    Some(field.name),
+                    Box::new(move |form| *(field.get_mut)(form) = Some(value)),
+                ));
+            }),
+        )
+    }
+
+    fn binding<V: Clone, U>(&self, field: FormField<T, V>, update: Callback<U>) -> FormBinding<V, U> {
+        let state = self.state.clone();
+        FormBinding {
+            name: field.name.into(),
+            value: self.get(field).clone(),
+            update,
+            onblur: Callback::from(move |_| state.dispatch(FormAction::Touch(field.name))),
+        }
+    }
+
+    /// Check whether the given field has lost focus since the form was created or reset.
+    pub fn is_touched<V>(&self, field: FormField<T, V>) -> bool {
+        self.state.touched.contains(field.name)
+    }
+
+    /// Check whether the field with the given name has lost focus since the form was created or reset.
+    pub(crate) fn is_touched_name(&self, name: &str) -> bool {
+        self.state.touched.contains(name)
+    }
+
+    /// Check whether the field with the given name has been updated since the form was created or
+    /// reset.
+    pub(crate) fn is_changed_name(&self, name: &str) -> bool {
+        self.state.changed.contains(name)
+    }
+
+    /// Check whether the given field differs from its initial value.
+    pub fn is_field_dirty<V: PartialEq>(&self, field: FormField<T, V>) -> bool {
+        (field.get)(&self.state.value) != (field.get)(&self.state.initial)
+    }
+
+    /// Check whether any field differs from its initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.state.value != self.state.initial
+    }
+
+    /// Check whether the form has been submitted since it was created or reset.
+    pub fn is_submitted(&self) -> bool {
+        self.state.submitted
+    }
+
+    /// Reset the form to its initial value, clearing its touched & submitted state.
+    pub fn reset(&self) {
+        self.state.dispatch(FormAction::Reset(None));
+    }
+
+    /// Reset the form to the given value, which becomes its initial value.
+    pub fn reset_to(&self, initial: T) {
+        self.state.dispatch(FormAction::Reset(Some(initial)));
+    }
+
+    /// Mark the form as submitted & return its current value.
+    pub fn submit(&self) -> Rc<T> {
+        self.state.dispatch(FormAction::Submit);
+        self.value()
+    }
+
+    /// A `submit` event handler for a `<form>` element, which prevents the default submission &
+    /// invokes `onsubmit` with the current value of the form.
+    pub fn onsubmit(&self, onsubmit: Callback<Rc<T>>) -> Callback<SubmitEvent> {
+        let handle = self.clone();
+        Callback::from(move |event: SubmitEvent| {
+            event.prevent_default();
+            onsubmit.emit(handle.submit());
+        })
+    }
+}
+
+/// A hook holding the state of a form as a single struct, which starts out as `T::default()`.
+///
+/// Each field of the struct is bound to a form control via `UseFormHandle::bind` & a
+/// `FormField`, which provides the `value`, `update` & `onblur` props of the control. The handle
+/// tracks which fields have been touched, i.e. have lost focus, & which fields are dirty, i.e.
+/// differ from their initial value.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use ybc::{use_form, Button, Checkbox, FormField, Input};
+///
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Signup {
+///     email: String,
+///     terms: bool,
+/// }
+///
+/// const EMAIL: FormField<Signup, String> = FormField::new("email", |form| &form.email, |form| &mut form.email);
+/// const TERMS: FormField<Signup, bool> = FormField::new("terms", |form| &form.terms, |form| &mut form.terms);
+///
+/// #[function_component(SignupForm)]
+/// fn signup_form() -> Html {
+///     let form = use_form::<Signup>();
+///     let (email, terms) = (form.bind(EMAIL), form.bind(TERMS));
+///     let onsubmit = form.onsubmit(Callback::from(|signup| { /* ... */ }));
+///     html! {
+///         <form {onsubmit}>
+///             <Input name={email.name} value={email.value} update={email.update} onblur={email.onblur} />
+///             <Checkbox name={terms.name} checked={terms.value} update={terms.update} onblur={terms.onblur}>
+///                 {"I agree to the terms"}
+///             </Checkbox>
+///             <Button disabled={!form.is_dirty()}>{"Sign up"}</Button>
+///         </form>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_form<T>() -> UseFormHandle<T>
+where
+    T: Clone + Default + PartialEq + 'static,
+{
+    use_form_with(T::default)
+}
+
+/// A hook holding the state of a form as a single struct, which starts out as the value returned
+/// by `init`; see `use_form`.
+#[hook]
+pub fn use_form_with<T, F>(init: F) -> UseFormHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+    F: FnOnce() -> T,
+{
+    let state = use_reducer(move || {
+        let initial = Rc::new(init());
+        FormState {
+            value: initial.clone(),
+            initial,
+            touched: HashSet::new(),
+            changed: HashSet::new(),
+            submitted: false,
+        }
+    });
+    UseFormHandle { state }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Signup {
+        email: String,
+    }
+
+    fn reduce(state: Rc<FormState<Signup>>, action: FormAction<Signup>) -> Rc<FormState<Signup>> {
+        state.reduce(action)
+    }
+
+    fn state() -> Rc<FormState<Signup>> {
+        let initial = Rc::new(Signup::default());
+        Rc::new(FormState {
+            value: initial.clone(),
+            initial,
+            touched: HashSet::new(),
+            changed: HashSet::new(),
+            submitted: false,
+        })
+    }
+
+    #[test]
+    fn form_state_updates_and_resets() {
+        let state = reduce(state(), FormAction::Update(Some("email"), Box::new(|form| form.email = "a@b.c".into())));
+        let state = reduce(state, FormAction::Touch("email"));
+        let state = reduce(state, FormAction::Submit);
+        assert_eq!(state.value.email, "a@b.c");
+        assert_eq!(state.initial.email, "");
+        assert!(state.touched.contains("email") && state.changed.contains("email") && state.submitted);
+
+        let state = reduce(state, FormAction::Reset(None));
+        assert_eq!(state.value.email, "");
+        assert!(state.touched.is_empty() && state.changed.is_empty() && !state.submitted);
+
+        let state = reduce(state, FormAction::Reset(Some(Signup { email: "x@y.z".into() })));
+        assert_eq!(state.value.email, "x@y.z");
+        assert_eq!(state.initial.email, "x@y.z");
+    }
+
+    #[test]
+    fn form_state_is_unchanged_by_redundant_touches() {
+        let state = reduce(state(), FormAction::Touch("email"));
+        assert!(Rc::ptr_eq(&state, &reduce(state.clone(), FormAction::Touch("email"))));
+    }
+}
@@ -2,7 +2,8 @@ use wasm_bindgen::UnwrapThrowExt;
 use web_sys::HtmlTextAreaElement;
 use yew::prelude::*;
 
-use crate::Size;
+use crate::form::validation::use_invalid;
+use crate::{Color, Size};
 
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct TextAreaProps {
@@ -25,6 +26,9 @@ pub struct TextAreaProps {
     /// The size of this component.
     #[prop_or_default]
     pub size: Option<Size>,
+    /// The color of this component.
+    #[prop_or_default]
+    pub color: Option<Color>,
     /// Fix the size of this component.
     #[prop_or_default]
     pub fixed_size: bool,
@@ -40,6 +44,9 @@ pub struct TextAreaProps {
     /// Make this component static.
     #[prop_or_default]
     pub r#static: bool,
+    /// The callback invoked when this element loses focus.
+    #[prop_or_default]
+    pub onblur: Callback<FocusEvent>,
 }
 
 /// A multiline textarea component.
@@ -51,10 +58,13 @@ pub struct TextAreaProps {
 /// component via callback.
 #[function_component(TextArea)]
 pub fn text_area(props: &TextAreaProps) -> Html {
+    let invalid = use_invalid(&props.name);
     let class = classes!(
         "textarea",
         props.classes.clone(),
+        invalid.then_some("is-danger"),
         props.size.as_ref().map(|size| size.to_string()),
+        props.color.as_ref().map(|color| color.to_string()),
         props.loading.then_some("is-loading"),
         props.r#static.then_some("is-static"),
         props.fixed_size.then_some("has-fixed-size"),
@@ -66,6 +76,8 @@ pub fn text_area(props: &TextAreaProps) -> Html {
     html! {
         <textarea
             name={props.name.clone()}
+            onblur={props.onblur.clone()}
+            aria-invalid={invalid.then_some("true")}
             value={props.value.clone()}
             {oninput}
             {class}
@@ -0,0 +1,770 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::{Color, FormField, Notification, UseFormHandle};
+
+/// A boxed future, as returned by the checks of an `AsyncRule`.
+type LocalFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+
+/// A function checking a value of type `V`.
+type CheckFn<V, R> = dyn Fn(&V) -> R;
+
+/// A function checking whether a field differs between two values of a form.
+type ChangedFn<T> = dyn Fn(&T, &T) -> bool;
+
+/// A synchronous validation rule for values of type `V`, as used by `Validation`.
+///
+/// All rules except `required` accept empty values, so that optional fields are only validated
+/// once they are filled in.
+pub struct Rule<V> {
+    check: Rc<CheckFn<V, Option<String>>>,
+}
+
+impl<V> Clone for Rule<V> {
+    fn clone(&self) -> Self {
+        Self { check: self.check.clone() }
+    }
+}
+
+impl<V> Rule<V> {
+    /// A rule failing with the message returned by `check`, if any.
+    pub fn custom(check: impl Fn(&V) -> Option<String> + 'static) -> Self {
+        Self { check: Rc::new(check) }
+    }
+
+    /// Check the given value, returning the message of this rule if it fails.
+    pub fn check(&self, value: &V) -> Option<String> {
+        (self.check)(value)
+    }
+
+    /// A rule failing with `message` when `valid` returns `false`.
+    fn new(message: impl Into<String>, valid: impl Fn(&V) -> bool + 'static) -> Self {
+        let message = message.into();
+        Self::custom(move |value| (!valid(value)).then(|| message.clone()))
+    }
+}
+
+impl<V: Blank> Rule<V> {
+    /// A rule failing when the value is blank, e.g. an empty string or an unchecked checkbox.
+    pub fn required(message: impl Into<String>) -> Self {
+        Self::new(message, |value: &V| !value.is_blank())
+    }
+}
+
+impl Rule<String> {
+    /// A rule failing when the value has fewer than `min` characters.
+    pub fn min_length(min: usize, message: impl Into<String>) -> Self {
+        Self::new(message, move |value: &String| value.is_empty() || value.chars().count() >= min)
+    }
+
+    /// A rule failing when the value has more than `max` characters.
+    pub fn max_length(max: usize, message: impl Into<String>) -> Self {
+        Self::new(message, move |value: &String| value.chars().count() <= max)
+    }
+
+    /// A rule failing when the value does not match the given regular expression.
+    ///
+    /// The expression matches anywhere within the value unless anchored via `^` & `$`.
+    #[cfg(feature = "regex")]
+    pub fn pattern(regex: regex::Regex, message: impl Into<String>) -> Self {
+        Self::new(message, move |value: &String| value.is_empty() || regex.is_match(value))
+    }
+
+    /// A rule failing when the value is not an email address.
+    pub fn email(message: impl Into<String>) -> Self {
+        Self::new(message, |value: &String| value.is_empty() || is_email(value))
+    }
+
+    /// A rule failing when the value is not a number within `min..=max`.
+    pub fn range(min: f64, max: f64, message: impl Into<String>) -> Self {
+        Self::new(message, move |value: &String| {
+            let value = value.trim();
+            value.is_empty()
+                || value
+                    .parse::<f64>()
+                    .map(|value| (min..=max).contains(&value))
+                    .unwrap_or(false)
+        })
+    }
+}
+
+/// Values which may be blank, as checked by `Rule::required`.
+pub trait Blank {
+    /// Check whether this value is blank.
+    fn is_blank(&self) -> bool;
+}
+
+impl Blank for String {
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+impl Blank for bool {
+    fn is_blank(&self) -> bool {
+        !self
+    }
+}
+
+impl<T> Blank for Option<T> {
+    fn is_blank(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl<T> Blank for Vec<T> {
+    fn is_blank(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Check whether the given value is shaped like an email address, i.e. `local@domain.tld`.
+fn is_email(value: &str) -> bool {
+    match value.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !value.chars().any(char::is_whitespace)
+                && !domain.contains('@')
+                && domain.contains('.')
+                && domain.split('.').all(|label| !label.is_empty())
+        }
+        None => false,
+    }
+}
+
+/// An asynchronous validation rule for values of type `V`, e.g. checking that a username is not
+/// taken, as used by `Validation`.
+pub struct AsyncRule<V> {
+    check: Rc<dyn Fn(V) -> LocalFuture<Option<String>>>,
+}
+
+impl<V> Clone for AsyncRule<V> {
+    fn clone(&self) -> Self {
+        Self { check: self.check.clone() }
+    }
+}
+
+impl<V> AsyncRule<V> {
+    /// A rule failing with the message resolved by `check`, if any.
+    pub fn new<F, Fut>(check: F) -> Self
+    where
+        F: Fn(V) -> Fut + 'static,
+        Fut: Future<Output = Option<String>> + 'static,
+    {
+        Self {
+            check: Rc::new(move |value| Box::pin(check(value))),
+        }
+    }
+}
+
+/// When the fields of a form are validated, as used by `Validation`.
+///
+/// All fields are validated when the form is submitted, & on every change afterwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidateOn {
+    /// Validate a field once it has been updated.
+    Change,
+    /// Validate a field once it has lost focus, & on every change afterwards.
+    #[default]
+    Blur,
+    /// Validate the fields only when the form is submitted.
+    Submit,
+}
+
+/// A check of a form against the rules of a single field.
+enum Check<T> {
+    Sync(Box<CheckFn<T, Vec<String>>>),
+    /// An async rule, along with a function checking whether the field differs between two values
+    /// of the form.
+    Async(Box<CheckFn<T, LocalFuture<Option<String>>>>, Box<ChangedFn<T>>),
+}
+
+/// The validation rules of a form struct `T`, as used by `use_validation`.
+pub struct Validation<T> {
+    on: ValidateOn,
+    fields: Vec<(&'static str, Check<T>)>,
+    form: Vec<Box<CheckFn<T, Option<String>>>>,
+}
+
+impl<T> Default for Validation<T> {
+    fn default() -> Self {
+        Self {
+            on: ValidateOn::default(),
+            fields: Vec::new(),
+            form: Vec::new(),
+        }
+    }
+}
+
+impl<T: 'static> Validation<T> {
+    /// A set of validation rules, validating each field once it has lost focus.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set when the fields are validated.
+    pub fn on(mut self, on: ValidateOn) -> Self {
+        self.on = on;
+        self
+    }
+
+    /// Validate the given field against the given rules, producing one message per failed rule.
+    pub fn field<V: 'static>(mut self, field: FormField<T, V>, rules: impl IntoIterator<Item = Rule<V>>) -> Self {
+        let rules = rules.into_iter().collect::<Vec<_>>();
+        let check = move |form: &T| rules.iter().filter_map(|rule| rule.check(field.value(form))).collect();
+        self.fields.push((field.name(), Check::Sync(Box::new(check))));
+        self
+    }
+
+    /// Validate the given field against the given async rule, whenever the field changes.
+    pub fn field_async<V: Clone + PartialEq + 'static>(mut self, field: FormField<T, V>, rule: AsyncRule<V>) -> Self {
+        let check = move |form: &T| (rule.check)(field.value(form).clone());
+        let changed = move |a: &T, b: &T| field.value(a) != field.value(b);
+        self.fields
+            .push((field.name(), Check::Async(Box::new(check), Box::new(changed))));
+        self
+    }
+
+    /// Validate the form as a whole, e.g. checking that two fields match, once it is submitted.
+    pub fn form(mut self, check: impl Fn(&T) -> Option<String> + 'static) -> Self {
+        self.form.push(Box::new(check));
+        self
+    }
+
+    /// The messages of the synchronous rules failed by the given form value, including the
+    /// form-level rules when `form` is `true`.
+    fn errors(&self, value: &T, active: impl Fn(&str) -> bool, form: bool) -> ValidationErrors {
+        let mut errors = ValidationErrors::default();
+        for (name, check) in &self.fields {
+            if let Check::Sync(check) = check {
+                if active(name) {
+                    for message in check(value) {
+                        errors.add_field_error(*name, message);
+                    }
+                }
+            }
+        }
+        if form {
+            for message in self.form.iter().filter_map(|check| check(value)) {
+                errors.add_form_error(message);
+            }
+        }
+        errors
+    }
+}
+
+/// The validation messages of a form, by field name & for the form as a whole.
+///
+/// Within a `Form`, these messages are rendered automatically: the controls with a failing field
+/// name get `is-danger` & `aria-invalid`, the `Field` with the same `name` renders the messages as
+/// its help, & the form-level messages are rendered in a `Notification`. Errors may also be built
+/// by hand, e.g. from the response of a server.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationErrors {
+    fields: HashMap<String, Vec<String>>,
+    form: Vec<String>,
+}
+
+impl ValidationErrors {
+    /// The messages of the field with the given name.
+    pub fn field(&self, name: &str) -> &[String] {
+        self.fields.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// The messages of the form as a whole.
+    pub fn form(&self) -> &[String] {
+        &self.form
+    }
+
+    /// Check whether there are no messages at all.
+    pub fn is_empty(&self) -> bool {
+        self.form.is_empty() && self.fields.values().all(Vec::is_empty)
+    }
+
+    /// Add a message to the field with the given name.
+    pub fn add_field_error(&mut self, name: impl Into<String>, message: impl Into<String>) {
+        self.fields.entry(name.into()).or_default().push(message.into());
+    }
+
+    /// Add a message to the form as a whole.
+    pub fn add_form_error(&mut self, message: impl Into<String>) {
+        self.form.push(message.into());
+    }
+}
+
+/// Check whether the control with the given name is invalid, according to the `ValidationErrors`
+/// of the enclosing `Form`.
+#[hook]
+pub(crate) fn use_invalid(name: &str) -> bool {
+    let errors = use_context::<ValidationErrors>();
+    errors.map(|errors| !errors.field(name).is_empty()).unwrap_or(false)
+}
+
+/// The results of the async rules of a form, by the index of the rule.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct AsyncResults {
+    /// The message of each resolved rule, if failed.
+    messages: HashMap<usize, Option<String>>,
+    /// The generation of the latest check of each rule; results of earlier checks are ignored.
+    generations: HashMap<usize, u64>,
+    /// The rules whose latest check is pending.
+    pending: HashMap<usize, bool>,
+}
+
+enum AsyncAction {
+    Check(usize, u64),
+    Resolve(usize, u64, Option<String>),
+}
+
+impl Reducible for AsyncResults {
+    type Action = AsyncAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            AsyncAction::Check(idx, generation) => {
+                state.generations.insert(idx, generation);
+                state.pending.insert(idx, true);
+            }
+            AsyncAction::Resolve(idx, generation, _) if self.generations.get(&idx) != Some(&generation) => return self,
+            AsyncAction::Resolve(idx, _, message) => {
+                state.messages.insert(idx, message);
+                state.pending.insert(idx, false);
+            }
+        }
+        Rc::new(state)
+    }
+}
+
+/// The state of the async rules shared by the checks of `use_validation`.
+struct AsyncChecks<T> {
+    results: UseReducerHandle<AsyncResults>,
+    /// The form value each rule was last checked against.
+    checked: Rc<RefCell<HashMap<usize, Rc<T>>>>,
+    /// The generation of the latest check.
+    generation: Rc<RefCell<u64>>,
+}
+
+impl<T> Clone for AsyncChecks<T> {
+    fn clone(&self) -> Self {
+        Self {
+            results: self.results.clone(),
+            checked: self.checked.clone(),
+            generation: self.generation.clone(),
+        }
+    }
+}
+
+impl<T: 'static> AsyncChecks<T> {
+    /// Check the given value against the async rule with the given index, returning the future
+    /// resolving its message.
+    fn check(&self, idx: usize, check: &CheckFn<T, LocalFuture<Option<String>>>, value: Rc<T>) -> impl Future<Output = Option<String>> {
+        let generation = {
+            let mut generation = self.generation.borrow_mut();
+            *generation += 1;
+            *generation
+        };
+        let future = check(&value);
+        self.checked.borrow_mut().insert(idx, value);
+        self.results.dispatch(AsyncAction::Check(idx, generation));
+        let results = self.results.clone();
+        async move {
+            let message = future.await;
+            results.dispatch(AsyncAction::Resolve(idx, generation, message.clone()));
+            message
+        }
+    }
+}
+
+/// A handle to the validation state of a form, as returned by `use_validation`.
+#[derive(Clone)]
+pub struct UseValidationHandle<T: Clone + PartialEq + 'static> {
+    form: UseFormHandle<T>,
+    validation: Rc<Validation<T>>,
+    errors: ValidationErrors,
+    checks: AsyncChecks<T>,
+}
+
+impl<T: Clone + PartialEq + 'static> PartialEq for UseValidationHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.form == other.form && Rc::ptr_eq(&self.validation, &other.validation) && self.errors == other.errors
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> UseValidationHandle<T> {
+    /// The current validation messages, for the `errors` prop of a `Form`.
+    pub fn errors(&self) -> ValidationErrors {
+        self.errors.clone()
+    }
+
+    /// The current messages of the given field.
+    pub fn field_errors<V>(&self, field: FormField<T, V>) -> &[String] {
+        self.errors.field(field.name())
+    }
+
+    /// Check whether any async rule is being checked.
+    pub fn is_pending(&self) -> bool {
+        self.checks.results.pending.values().any(|pending| *pending)
+    }
+
+    /// Check whether the form has no validation messages. Fields which are not validated yet are
+    /// not taken into account.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// A `submit` event handler for a `<form>` or `Form`, which prevents the default submission,
+    /// marks the form as submitted & validates all of its fields, including the form-level rules.
+    /// When all rules pass, including the async rules, `onsubmit` is invoked with the value of the
+    /// form.
+    pub fn onsubmit(&self, onsubmit: Callback<Rc<T>>) -> Callback<SubmitEvent> {
+        let handle = self.clone();
+        Callback::from(move |event: SubmitEvent| {
+            event.prevent_default();
+            let value = handle.form.submit();
+            if !handle.validation.errors(&value, |_| true, true).is_empty() {
+                return;
+            }
+            let checks = handle
+                .validation
+                .fields
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, (_, check))| match check {
+                    Check::Async(check, _) => Some(handle.checks.check(idx, check.as_ref(), value.clone())),
+                    Check::Sync(_) => None,
+                })
+                .collect::<Vec<_>>();
+            let onsubmit = onsubmit.clone();
+            yew::platform::spawn_local(async move {
+                let mut valid = true;
+                for check in checks {
+                    valid &= check.await.is_none();
+                }
+                if valid {
+                    onsubmit.emit(value);
+                }
+            });
+        })
+    }
+}
+
+/// A hook validating the value of a form held by `use_form`, against the rules built by `init`.
+///
+/// Fields are validated as given by `ValidateOn`, & all fields are validated when the form is
+/// submitted via `UseValidationHandle::onsubmit`. Synchronous rules are checked on every render,
+/// while async rules are checked whenever their field changes, with the results of outdated
+/// checks being discarded. Pass the messages of the handle to the `errors` prop of a `Form`, in
+/// order to render them.
+///
+/// The rules are only built once, so any values captured by them, e.g. from props or state, are
+/// those of the first render. Use `use_validation_with_deps` for rules depending on such values.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use ybc::{use_form, use_validation, Button, Control, Field, Form, FormField, Input, Rule, Validation};
+///
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Signup {
+///     email: String,
+///     password: String,
+///     confirm: String,
+/// }
+///
+/// const EMAIL: FormField<Signup, String> = FormField::new("email", |form| &form.email, |form| &mut form.email);
+/// const PASSWORD: FormField<Signup, String> = FormField::new("password", |form| &form.password, |form| &mut form.password);
+/// const CONFIRM: FormField<Signup, String> = FormField::new("confirm", |form| &form.confirm, |form| &mut form.confirm);
+///
+/// #[function_component(SignupForm)]
+/// fn signup_form() -> Html {
+///     let form = use_form::<Signup>();
+///     let validation = use_validation(&form, || {
+///         Validation::new()
+///             .field(EMAIL, [Rule::required("Email is required"), Rule::email("Email is invalid")])
+///             .field(PASSWORD, [Rule::min_length(8, "Password must have at least 8 characters")])
+///             .form(|signup: &Signup| (signup.password != signup.confirm).then(|| "Passwords do not match".into()))
+///     });
+///     let onsubmit = validation.onsubmit(Callback::from(|signup| { /* ... */ }));
+///     let (email, password, confirm) = (form.bind(EMAIL), form.bind(PASSWORD), form.bind(CONFIRM));
+///     html! {
+///         <Form errors={validation.errors()} {onsubmit}>
+///             <Field label={Some("Email".to_string())} name={Some("email".to_string())}>
+///                 <Control>
+///                     <Input name={email.name} value={email.value} update={email.update} onblur={email.onblur} />
+///                 </Control>
+///             </Field>
+///             <Field label={Some("Password".to_string())} name={Some("password".to_string())}>
+///                 <Control>
+///                     <Input name={password.name} value={password.value} update={password.update} onblur={password.onblur} />
+///                 </Control>
+///             </Field>
+///             <Input name={confirm.name} value={confirm.value} update={confirm.update} onblur={confirm.onblur} />
+///             <Button>{"Sign up"}</Button>
+///         </Form>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_validation<T, F>(form: &UseFormHandle<T>, init: F) -> UseValidationHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+    F: FnOnce() -> Validation<T>,
+{
+    use_validation_with_deps(form, |_| init(), ())
+}
+
+/// A hook validating the value of a form held by `use_form`, against the rules built by `init`,
+/// which are rebuilt whenever `deps` changes; see `use_validation`.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use ybc::{use_form, use_validation_with_deps, FormField, Rule, Validation};
+///
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Comment {
+///     text: String,
+/// }
+///
+/// const TEXT: FormField<Comment, String> = FormField::new("text", |form| &form.text, |form| &mut form.text);
+///
+/// #[derive(Properties, PartialEq)]
+/// struct CommentFormProps {
+///     max_length: usize,
+/// }
+///
+/// #[function_component(CommentForm)]
+/// fn comment_form(props: &CommentFormProps) -> Html {
+///     let form = use_form::<Comment>();
+///     let validation = use_validation_with_deps(
+///         &form,
+///         |&max_length| Validation::new().field(TEXT, [Rule::max_length(max_length, "Comment is too long")]),
+///         props.max_length,
+///     );
+///     // ...
+///     # html! {}
+/// }
+/// ```
+#[hook]
+pub fn use_validation_with_deps<T, F, D>(form: &UseFormHandle<T>, init: F, deps: D) -> UseValidationHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+    F: FnOnce(&D) -> Validation<T>,
+    D: PartialEq + 'static,
+{
+    let validation = use_memo(init, deps);
+    let checks = AsyncChecks {
+        results: use_reducer(AsyncResults::default),
+        checked: use_mut_ref(HashMap::new),
+        generation: use_mut_ref(|| 0),
+    };
+
+    // Async rules are tracked by their index, so their results are discarded once the rules are
+    // rebuilt.
+    let rules = use_mut_ref(|| validation.clone());
+    if !Rc::ptr_eq(&rules.borrow(), &validation) {
+        checks.checked.borrow_mut().clear();
+        *rules.borrow_mut() = validation.clone();
+    }
+
+    let value = form.value();
+    let active = {
+        let (form, on) = (form.clone(), validation.on);
+        move |name: &str| {
+            form.is_submitted()
+                || match on {
+                    ValidateOn::Change => form.is_changed_name(name),
+                    ValidateOn::Blur => form.is_touched_name(name),
+                    ValidateOn::Submit => false,
+                }
+        }
+    };
+    let mut errors = validation.errors(&value, &active, form.is_submitted());
+    // The async rules of the active fields, which are checked unless checked against the same
+    // field value already.
+    let mut unchecked = Vec::new();
+    for (idx, (name, check)) in validation.fields.iter().enumerate() {
+        if let Check::Async(_, changed) = check {
+            if !active(name) {
+                continue;
+            }
+            match checks.checked.borrow().get(&idx) {
+                Some(checked) if !changed(checked, &value) => {
+                    let pending = checks.results.pending.get(&idx).copied().unwrap_or(false);
+                    if let (false, Some(Some(message))) = (pending, checks.results.messages.get(&idx)) {
+                        errors.add_field_error(*name, message.clone());
+                    }
+                }
+                _ => unchecked.push(idx),
+            }
+        }
+    }
+
+    {
+        let (validation, checks) = (validation.clone(), checks.clone());
+        use_effect_with_deps(
+            move |(unchecked, value): &(Vec<usize>, Rc<T>)| {
+                for &idx in unchecked {
+                    if let (_, Check::Async(check, _)) = &validation.fields[idx] {
+                        let future = checks.check(idx, check.as_ref(), value.clone());
+                        yew::platform::spawn_local(async move {
+                            future.await;
+                        });
+                    }
+                }
+            },
+            (unchecked, value),
+        );
+    }
+
+    UseValidationHandle { form: form.clone(), validation, errors, checks }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug, Properties, PartialEq)]
+pub struct FormProps {
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// The validation messages of this form, typically from `UseValidationHandle::errors`.
+    #[prop_or_default]
+    pub errors: ValidationErrors,
+    /// The handler of the `submit` event, typically from `UseValidationHandle::onsubmit`.
+    #[prop_or_default]
+    pub onsubmit: Callback<SubmitEvent>,
+}
+
+/// A `<form>` element rendering the given validation messages.
+///
+/// The messages of each field are provided to the form controls & `Field`s within this form, which
+/// render them based on the `name` of the control. Form-level messages are rendered in a danger
+/// `Notification` at the top of the form. Browser validation is disabled via `novalidate`.
+#[function_component(Form)]
+pub fn form(props: &FormProps) -> Html {
+    let messages = match props.errors.form() {
+        [] => html! {},
+        messages => html! {
+            <Notification color={Color::DangerLight}>
+                {for messages.iter().map(|message| html! {<p>{message.clone()}</p>})}
+            </Notification>
+        },
+    };
+    html! {
+        <form class={props.classes.clone()} novalidate=true onsubmit={props.onsubmit.clone()}>
+            {messages}
+            <ContextProvider<ValidationErrors> context={props.errors.clone()}>
+                {props.children.clone()}
+            </ContextProvider<ValidationErrors>>
+        </form>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_rule_checks_blank_values() {
+        let rule = Rule::<String>::required("required");
+        assert_eq!(rule.check(&"".into()), Some("required".into()));
+        assert_eq!(rule.check(&"  ".into()), Some("required".into()));
+        assert_eq!(rule.check(&"value".into()), None);
+        assert_eq!(Rule::<bool>::required("required").check(&false), Some("required".into()));
+        assert_eq!(Rule::<Option<String>>::required("required").check(&Some("".into())), None);
+    }
+
+    #[test]
+    fn string_rules_accept_empty_values() {
+        let rules = [Rule::min_length(3, "min"), Rule::email("email"), Rule::range(1.0, 10.0, "range")];
+        assert!(rules.iter().all(|rule| rule.check(&String::new()).is_none()));
+    }
+
+    #[test]
+    fn string_rules_check_values() {
+        assert_eq!(Rule::min_length(3, "min").check(&"ab".into()), Some("min".into()));
+        assert_eq!(Rule::min_length(3, "min").check(&"äbc".into()), None);
+        assert_eq!(Rule::max_length(3, "max").check(&"abcd".into()), Some("max".into()));
+        assert_eq!(Rule::range(1.0, 10.0, "range").check(&"10".into()), None);
+        assert_eq!(Rule::range(1.0, 10.0, "range").check(&"10.5".into()), Some("range".into()));
+        assert_eq!(Rule::range(1.0, 10.0, "range").check(&"ten".into()), Some("range".into()));
+    }
+
+    #[test]
+    fn email_addresses() {
+        assert!(is_email("ada@example.com"));
+        assert!(is_email("ada.lovelace+ybc@mail.example.org"));
+        assert!(!is_email("ada"));
+        assert!(!is_email("@example.com"));
+        assert!(!is_email("ada@example"));
+        assert!(!is_email("ada@example..com"));
+        assert!(!is_email("ada@@example.com"));
+        assert!(!is_email("ada lovelace@example.com"));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn pattern_rule() {
+        let rule = Rule::pattern(regex::Regex::new("^[a-z]+$").unwrap(), "pattern");
+        assert_eq!(rule.check(&"abc".into()), None);
+        assert_eq!(rule.check(&"ABC".into()), Some("pattern".into()));
+    }
+
+    #[derive(Clone, Default, PartialEq)]
+    struct Signup {
+        email: String,
+        password: String,
+        confirm: String,
+    }
+
+    const EMAIL: FormField<Signup, String> = FormField::new("email", |form| &form.email, |form| &mut form.email);
+    const PASSWORD: FormField<Signup, String> = FormField::new("password", |form| &form.password, |form| &mut form.password);
+
+    #[test]
+    fn validation_checks_active_fields_and_form_rules() {
+        let validation = Validation::new()
+            .field(EMAIL, [Rule::required("required"), Rule::email("email")])
+            .field(PASSWORD, [Rule::min_length(8, "short")])
+            .field(
+                PASSWORD,
+                [Rule::custom(|value: &String| {
+                    (!value.chars().any(|c| c.is_ascii_digit())).then(|| "digit".into())
+                })],
+            )
+            .form(|form: &Signup| (form.password != form.confirm).then(|| "mismatch".into()));
+        let value = Signup {
+            email: "ada".into(),
+            password: "secret".into(),
+            confirm: "".into(),
+        };
+
+        let errors = validation.errors(&value, |name| name == "email", false);
+        assert_eq!(errors.field("email"), ["email"]);
+        assert!(errors.field("password").is_empty() && errors.form().is_empty());
+
+        let errors = validation.errors(&value, |_| true, true);
+        assert_eq!(errors.field("password"), ["short", "digit"]);
+        assert_eq!(errors.form(), ["mismatch"]);
+        assert!(!errors.is_empty());
+        assert!(validation.errors(&Signup::default(), |_| false, false).is_empty());
+    }
+
+    #[test]
+    fn async_results_ignore_outdated_checks() {
+        let state = Rc::new(AsyncResults::default());
+        let state = state.reduce(AsyncAction::Check(0, 1));
+        let state = state.reduce(AsyncAction::Check(0, 2));
+        let state = state.reduce(AsyncAction::Resolve(0, 1, Some("outdated".into())));
+        assert_eq!(state.messages.get(&0), None);
+        assert_eq!(state.pending.get(&0), Some(&true));
+        let state = state.reduce(AsyncAction::Resolve(0, 2, Some("taken".into())));
+        assert_eq!(state.messages.get(&0), Some(&Some("taken".into())));
+        assert_eq!(state.pending.get(&0), Some(&false));
+    }
+}
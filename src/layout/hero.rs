@@ -1,6 +1,10 @@
+#![allow(non_local_definitions)]
+
 use derive_more::Display;
 use yew::prelude::*;
 
+use crate::Color;
+
 #[derive(Clone, Debug, Properties, PartialEq)]
 pub struct HeroProps {
     /// Extra classes for the hero container.
@@ -33,6 +37,9 @@ pub struct HeroProps {
     /// Generate a subtle gradient for the hero.
     #[prop_or_default]
     pub bold: bool,
+    /// The color of this hero.
+    #[prop_or_default]
+    pub color: Option<Color>,
     /// The size for this hero.
     #[prop_or_default]
     pub size: Option<HeroSize>,
@@ -48,6 +55,7 @@ pub fn hero(props: &HeroProps) -> Html {
         props.classes.clone(),
         props.fixed_nav.then_some("is-fullheight-with-navbar"),
         props.bold.then_some("is-bold"),
+        props.color.as_ref().map(|color| color.to_string()),
         props.size.as_ref().map(|size| size.to_string()),
     );
 
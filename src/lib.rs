@@ -24,38 +24,67 @@ mod components;
 mod elements;
 mod form;
 mod layout;
+#[cfg(feature = "router")]
+mod router;
 
 // columns
-pub use columns::{Column, ColumnProps, Columns, ColumnsProps};
+pub use columns::{Column, ColumnGap, ColumnOffset, ColumnProps, ColumnSize, Columns, ColumnsActivation, ColumnsProps};
 
 // common
-pub use common::{Alignment, Size};
+pub use common::{Alignment, Color, Size};
 
 // components
-pub use components::breadcrumb::{Breadcrumb, BreadcrumbProps, BreadcrumbSeparator, BreadcrumbSize};
+pub use components::breadcrumb::{Breadcrumb, BreadcrumbProps, BreadcrumbSeparator, BreadcrumbSize, Crumb};
+#[cfg(feature = "router")]
+pub use components::breadcrumb::{BreadcrumbRouter, BreadcrumbRouterProps, RouterCrumb};
 pub use components::card::{
     Card, CardContent, CardContentProps, CardFooter, CardFooterProps, CardHeader, CardHeaderProps, CardImage, CardImageProps, CardProps,
 };
+#[cfg(feature = "router")]
+pub use components::card::{CardRouter, CardRouterProps};
+pub use components::dialog::{use_dialog, DialogContext, DialogFuture, DialogOptions, DialogProvider, DialogProviderProps};
 pub use components::dropdown::{Dropdown, DropdownMsg, DropdownProps};
-pub use components::menu::{Menu, MenuLabel, MenuLabelProps, MenuList, MenuListProps, MenuProps};
+#[cfg(feature = "router")]
+pub use components::dropdown::{DropdownItemRouter, DropdownItemRouterProps};
+pub use components::menu::{Menu, MenuItem, MenuLabel, MenuLabelProps, MenuList, MenuListProps, MenuProps, MenuSection, MenuTree, MenuTreeProps};
+#[cfg(feature = "router")]
+pub use components::menu::{MenuRouter, MenuRouterProps};
 pub use components::message::{Message, MessageBody, MessageBodyProps, MessageHeader, MessageHeaderProps, MessageProps};
-pub use components::modal::{Modal, ModalCard, ModalCardProps, ModalCloseMsg, ModalCloser, ModalMsg, ModalProps};
+#[allow(deprecated)]
+pub use components::modal::ModalMsg;
+pub use components::modal::{
+    use_modal, Modal, ModalCard, ModalCardProps, ModalContext, ModalProps, ModalProvider, ModalProviderProps, UseModalHandle,
+};
+#[cfg(feature = "router")]
+pub use components::modal::{use_route_modal, RouteModal, UseRouteModalHandle};
+#[cfg(feature = "agent")]
+pub use components::modal::{ModalCloseMsg, ModalCloser};
 pub use components::navbar::{
     Navbar, NavbarDivider, NavbarDividerProps, NavbarDropdown, NavbarDropdownProps, NavbarFixed, NavbarItem, NavbarItemProps, NavbarItemTag,
     NavbarMsg, NavbarProps,
 };
-pub use components::pagination::{
-    Pagination, PaginationEllipsis, PaginationItem, PaginationItemProps, PaginationItemRouter, PaginationItemType, PaginationProps,
-};
+#[cfg(feature = "router")]
+pub use components::navbar::{NavbarItemRouter, NavbarItemRouterProps};
+pub use components::pagination::{Pagination, PaginationEllipsis, PaginationItem, PaginationItemProps, PaginationItemType, PaginationProps};
+#[cfg(feature = "router")]
+pub use components::pagination::{PaginationItemRouter, PaginationItemRouterProps, PaginationRouter, PaginationRouterProps};
 pub use components::panel::{Panel, PanelBlock, PanelBlockProps, PanelProps, PanelTabs, PanelTabsProps};
+#[cfg(feature = "router")]
+pub use components::panel::{PanelBlockRouter, PanelBlockRouterProps};
+#[cfg(feature = "router")]
+pub use components::tabs::{RouterTab, TabsRouter, TabsRouterProps};
 pub use components::tabs::{Tabs, TabsProps};
+pub use components::toast::{use_toasts, Toast, ToastAction, ToastContext, ToastId, ToastPosition, ToastProvider, ToastProviderProps};
+pub use components::virtual_scroll::{RowHeight, VirtualScroll, VirtualScrollProps, Virtualize};
 
 // elements
 pub use elements::block::{Block, BlockProps};
 pub use elements::button::{
-    Button, ButtonAnchor, ButtonAnchorProps, ButtonAnchorRouter, ButtonGroupSize, ButtonInputReset, ButtonInputResetProps, ButtonInputSubmit,
-    ButtonInputSubmitProps, ButtonProps, ButtonRouter, ButtonRouterProps, Buttons, ButtonsProps,
+    Button, ButtonAnchor, ButtonAnchorProps, ButtonGroupSize, ButtonInputReset, ButtonInputResetProps, ButtonInputSubmit, ButtonInputSubmitProps,
+    ButtonProps, Buttons, ButtonsProps,
 };
+#[cfg(feature = "router")]
+pub use elements::button::{ButtonAnchorRouter, ButtonRouter, ButtonRouterProps};
 pub use elements::content::{Content, ContentProps};
 pub use elements::delete::{Delete, DeleteProps};
 pub use elements::icon::{Icon, IconProps};
@@ -63,8 +92,10 @@ pub use elements::image::{Image, ImageProps, ImageSize};
 pub use elements::notification::{Notification, NotificationProps};
 pub use elements::progress::{Progress, ProgressProps};
 pub use elements::r#box::{Box, BoxProps};
-pub use elements::table::{Table, TableProps};
+pub use elements::table::{DataTable, DataTableColumn, DataTableProps, SortDirection, Table, TableProps, TableSort};
 pub use elements::tag::{Tag, TagProps, Tags, TagsProps};
+#[cfg(feature = "router")]
+pub use elements::tag::{TagRouter, TagRouterProps};
 pub use elements::title::{HeaderSize, Subtitle, SubtitleProps, Title, TitleProps};
 
 // form
@@ -75,7 +106,11 @@ pub use form::file::{File, FileProps};
 pub use form::input::{Input, InputProps, InputType};
 pub use form::radio::{Radio, RadioProps};
 pub use form::select::{MultiSelect, MultiSelectProps, Select, SelectProps};
+pub use form::state::{use_form, use_form_with, FormBinding, FormField, UseFormHandle};
 pub use form::textarea::{TextArea, TextAreaProps};
+pub use form::validation::{
+    use_validation, use_validation_with_deps, AsyncRule, Blank, Form, FormProps, Rule, UseValidationHandle, ValidateOn, Validation, ValidationErrors,
+};
 
 // layout
 pub use layout::container::{Container, ContainerProps};
@@ -85,3 +120,7 @@ pub use layout::level::{Level, LevelItem, LevelItemProps, LevelLeft, LevelLeftPr
 pub use layout::media::{Media, MediaContent, MediaContentProps, MediaLeft, MediaLeftProps, MediaProps, MediaRight, MediaRightProps};
 pub use layout::section::{Section, SectionProps, SectionSize};
 pub use layout::tile::{Tile, TileCtx, TileProps, TileSize};
+
+// router
+#[cfg(feature = "router")]
+pub use router::RouteMatch;
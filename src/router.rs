@@ -0,0 +1,191 @@
+//! Helpers shared by the components which integrate with the Yew Router.
+
+use serde::Serialize;
+use yew::prelude::*;
+use yew_router::hooks::{use_location, use_navigator};
+use yew_router::navigator::{Navigator, NavigatorKind};
+use yew_router::Routable;
+
+/// How the current location is matched against the route of a router-aware component, in order
+/// to mark it as active.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RouteMatch {
+    /// The component is active when the current path is exactly the path of its route.
+    #[default]
+    Exact,
+    /// The component is active when the current path is the path of its route or any path below
+    /// it, e.g. `/posts/42` for the route at `/posts`.
+    Prefix,
+}
+
+/// Check whether the given current path matches the path of a route.
+pub(crate) fn route_matches(current: &str, target: &str, route_match: RouteMatch) -> bool {
+    let (current, target) = (current.trim_end_matches('/'), target.trim_end_matches('/'));
+    match route_match {
+        RouteMatch::Exact => current == target,
+        RouteMatch::Prefix => {
+            target.is_empty()
+                || current == target
+                || current
+                    .strip_prefix(target)
+                    .map(|rest| rest.starts_with('/'))
+                    .unwrap_or(false)
+        }
+    }
+}
+
+/// The index of the most specific of the given route paths matching the current path, i.e. the
+/// longest one; the first one wins among equally long paths.
+pub(crate) fn best_match<I>(current: &str, targets: impl IntoIterator<Item = (I, String)>, route_match: RouteMatch) -> Option<I> {
+    targets
+        .into_iter()
+        .filter(|(_, target)| route_matches(current, target, route_match))
+        .min_by_key(|(_, target)| std::cmp::Reverse(target.trim_end_matches('/').len()))
+        .map(|(idx, _)| idx)
+}
+
+/// The path of the current location, without the basename of the router.
+///
+/// Returns `None` when used outside of a `Router`.
+#[hook]
+pub(crate) fn use_current_path() -> Option<String> {
+    let location = use_location();
+    let navigator = use_navigator();
+    let (location, navigator) = (location?, navigator?);
+    let path = location.path();
+    let path = navigator
+        .basename()
+        .and_then(|basename| path.strip_prefix(basename))
+        .unwrap_or(path);
+    Some(path.to_string())
+}
+
+/// Check whether the current location matches the given route.
+///
+/// Returns `false` when used outside of a `Router`.
+#[hook]
+pub(crate) fn use_route_active<R>(route: &R, route_match: RouteMatch) -> bool
+where
+    R: Routable + 'static,
+{
+    use_current_path()
+        .map(|path| route_matches(&path, &route.to_path(), route_match))
+        .unwrap_or(false)
+}
+
+/// The `href` of a link to the given route & query, as rendered by the Yew Router `Link`
+/// component.
+pub(crate) fn route_href<R: Routable, Q: Serialize>(navigator: &Navigator, route: &R, query: Option<&Q>) -> String {
+    let mut href = format!("{}{}", navigator.basename().unwrap_or_default(), route.to_path());
+    if let Some(query) = query.and_then(|query| serde_urlencoded::to_string(query).ok()) {
+        if !query.is_empty() {
+            href = format!("{}?{}", href, query);
+        }
+    }
+    match navigator.kind() {
+        NavigatorKind::Hash => format!("#{}", href),
+        _ => href,
+    }
+}
+
+/// A click handler navigating to the given route & query, instead of following the link.
+pub(crate) fn route_onclick<R, Q>(navigator: Navigator, route: R, query: Option<Q>) -> Callback<MouseEvent>
+where
+    R: Routable + 'static,
+    Q: Serialize + 'static,
+{
+    Callback::from(move |event: MouseEvent| {
+        event.prevent_default();
+        match &query {
+            // Navigation only fails if the query cannot be serialized, in which case the link has
+            // no query either.
+            Some(query) => {
+                let _ = navigator.push_with_query(&route, query);
+            }
+            None => navigator.push(&route),
+        }
+    })
+}
+
+/// The given query parameters with the `param` parameter set to `value`, in place of its first
+/// occurrence & dropping any later ones, or appended when absent.
+pub(crate) fn with_param(params: &[(String, String)], param: &str, value: String) -> Vec<(String, String)> {
+    let mut params = params.to_vec();
+    match params.iter().position(|(name, _)| name == param) {
+        Some(idx) => {
+            params[idx].1 = value;
+            let mut seen = 0;
+            params.retain(|(name, _)| {
+                seen += (name == param) as usize;
+                name != param || seen == 1
+            });
+        }
+        None => params.push((param.into(), value)),
+    }
+    params
+}
+
+/// The given query parameters without any occurrence of the `param` parameter.
+pub(crate) fn without_param(params: &[(String, String)], param: &str) -> Vec<(String, String)> {
+    params.iter().filter(|(name, _)| name != param).cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn route_matches_exactly() {
+        assert!(route_matches("/posts", "/posts", RouteMatch::Exact));
+        assert!(route_matches("/posts/", "/posts", RouteMatch::Exact));
+        assert!(route_matches("/", "/", RouteMatch::Exact));
+        assert!(!route_matches("/posts/42", "/posts", RouteMatch::Exact));
+        assert!(!route_matches("/posts", "/", RouteMatch::Exact));
+    }
+
+    #[test]
+    fn route_matches_by_prefix() {
+        assert!(route_matches("/posts", "/posts", RouteMatch::Prefix));
+        assert!(route_matches("/posts/42/edit", "/posts", RouteMatch::Prefix));
+        assert!(route_matches("/posts", "/", RouteMatch::Prefix));
+        assert!(!route_matches("/postscript", "/posts", RouteMatch::Prefix));
+        assert!(!route_matches("/", "/posts", RouteMatch::Prefix));
+    }
+
+    #[test]
+    fn best_match_prefers_the_longest_path() {
+        let targets = || vec![(0, "/".to_string()), (1, "/posts".to_string()), (2, "/posts".to_string())];
+        assert_eq!(best_match("/posts/42", targets(), RouteMatch::Prefix), Some(1));
+        assert_eq!(best_match("/about", targets(), RouteMatch::Prefix), Some(0));
+        assert_eq!(best_match("/posts/42", targets(), RouteMatch::Exact), None);
+    }
+
+    fn params(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn with_param_keeps_other_query_params() {
+        assert_eq!(with_param(&[], "page", "2".into()), params(&[("page", "2")]));
+        assert_eq!(
+            with_param(&params(&[("q", "rust"), ("page", "1"), ("sort", "name")]), "page", "3".into()),
+            params(&[("q", "rust"), ("page", "3"), ("sort", "name")])
+        );
+        assert_eq!(
+            with_param(&params(&[("page", "1"), ("q", "rust"), ("page", "4")]), "page", "2".into()),
+            params(&[("page", "2"), ("q", "rust")])
+        );
+    }
+
+    #[test]
+    fn without_param_keeps_other_query_params() {
+        assert_eq!(without_param(&[], "edit"), params(&[]));
+        assert_eq!(
+            without_param(&params(&[("edit", "1"), ("q", "rust"), ("edit", "2")]), "edit"),
+            params(&[("q", "rust")])
+        );
+    }
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use common::Snapshot;
+use ybc::{ColumnGap, ColumnOffset, ColumnSize, ColumnsActivation};
+use yew::prelude::*;
+
+#[tokio::test]
+async fn columns() {
+    Snapshot::default()
+        .case("default", || {
+            html! {
+                <ybc::Columns>
+                    <ybc::Column>{"first"}</ybc::Column>
+                    <ybc::Column>{"second"}</ybc::Column>
+                </ybc::Columns>
+            }
+        })
+        .case("classes", || html! {<ybc::Columns classes={classes!("custom")} />})
+        .case("vcentered", || html! {<ybc::Columns vcentered=true />})
+        .case("multiline", || html! {<ybc::Columns multiline=true />})
+        .case("centered", || html! {<ybc::Columns centered=true />})
+        .case("gapless", || html! {<ybc::Columns gapless=true />})
+        .variants(
+            "gap",
+            [
+                ColumnGap::Zero,
+                ColumnGap::One,
+                ColumnGap::Two,
+                ColumnGap::Three,
+                ColumnGap::Four,
+                ColumnGap::Five,
+                ColumnGap::Six,
+                ColumnGap::Seven,
+                ColumnGap::Eight,
+            ],
+            |gap| html! {<ybc::Columns {gap} />},
+        )
+        .variants("activation", [ColumnsActivation::Mobile, ColumnsActivation::Desktop], |activation| {
+            html! {<ybc::Columns {activation} />}
+        })
+        .assert("columns")
+        .await;
+}
+
+#[tokio::test]
+async fn column() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Column>{"content"}</ybc::Column>})
+        .case("classes", || html! {<ybc::Column classes={classes!("custom")} />})
+        .variants(
+            "size",
+            [
+                ColumnSize::ThreeQuarters,
+                ColumnSize::TwoThirds,
+                ColumnSize::Half,
+                ColumnSize::OneThird,
+                ColumnSize::OneQuarter,
+                ColumnSize::Full,
+                ColumnSize::FourFifths,
+                ColumnSize::ThreeFifths,
+                ColumnSize::TwoFifths,
+                ColumnSize::OneFifth,
+                ColumnSize::One,
+                ColumnSize::Two,
+                ColumnSize::Three,
+                ColumnSize::Four,
+                ColumnSize::Five,
+                ColumnSize::Six,
+                ColumnSize::Seven,
+                ColumnSize::Eight,
+                ColumnSize::Nine,
+                ColumnSize::Ten,
+                ColumnSize::Eleven,
+                ColumnSize::Twelve,
+            ],
+            |size| html! {<ybc::Column {size} />},
+        )
+        .case("size per breakpoint", || {
+            html! {
+                <ybc::Column
+                    size_mobile={ColumnSize::Full}
+                    size_tablet={ColumnSize::Half}
+                    size_touch={ColumnSize::TwoThirds}
+                    size_desktop={ColumnSize::Four}
+                    size_widescreen={ColumnSize::OneThird}
+                    size_fullhd={ColumnSize::Three}
+                />
+            }
+        })
+        .case("offset", || html! {<ybc::Column offset={ColumnOffset::OneQuarter} />})
+        .case("offset per breakpoint", || {
+            html! {
+                <ybc::Column
+                    offset_mobile={ColumnOffset::One}
+                    offset_tablet={ColumnOffset::Two}
+                    offset_touch={ColumnOffset::Half}
+                    offset_desktop={ColumnOffset::OneFifth}
+                    offset_widescreen={ColumnOffset::Three}
+                    offset_fullhd={ColumnOffset::TwoFifths}
+                />
+            }
+        })
+        .case("narrow", || html! {<ybc::Column narrow=true />})
+        .case("narrow per breakpoint", || {
+            html! {
+                <ybc::Column
+                    narrow_mobile=true
+                    narrow_tablet=true
+                    narrow_touch=true
+                    narrow_desktop=true
+                    narrow_widescreen=true
+                    narrow_fullhd=true
+                />
+            }
+        })
+        .assert("column")
+        .await;
+}
@@ -0,0 +1,137 @@
+//! Shared harness for rendering components to HTML on a native target.
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew::LocalServerRenderer;
+
+/// The environment variable which causes snapshots to be (re)written instead of compared.
+const UPDATE_SNAPSHOTS_VAR: &str = "YBC_UPDATE_SNAPSHOTS";
+
+#[derive(Properties)]
+struct HarnessProps {
+    view: Rc<dyn Fn() -> Html>,
+}
+
+impl PartialEq for HarnessProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.view, &other.view)
+    }
+}
+
+#[function_component(Harness)]
+fn harness(props: &HarnessProps) -> Html {
+    (props.view)()
+}
+
+/// Render the HTML produced by the given closure to a string.
+pub async fn render(view: impl Fn() -> Html + 'static) -> String {
+    let props = HarnessProps { view: Rc::new(view) };
+    let renderer = LocalServerRenderer::<Harness>::with_props(props).hydratable(false);
+    tokio::task::LocalSet::new().run_until(renderer.render()).await
+}
+
+/// A set of named renders, compared as a whole against a checked-in snapshot.
+#[derive(Default)]
+pub struct Snapshot {
+    cases: Vec<(String, Rc<dyn Fn() -> Html>)>,
+}
+
+impl Snapshot {
+    /// Add a named case to this snapshot.
+    pub fn case(mut self, name: impl Into<String>, view: impl Fn() -> Html + 'static) -> Self {
+        self.cases.push((name.into(), Rc::new(view)));
+        self
+    }
+
+    /// Add one case per given value, named after the value's `Debug` representation.
+    pub fn variants<T, F>(mut self, prop: &str, values: impl IntoIterator<Item = T>, view: F) -> Self
+    where
+        T: std::fmt::Debug + Clone + 'static,
+        F: Fn(T) -> Html + Clone + 'static,
+    {
+        for value in values {
+            let name = format!("{}={:?}", prop, value);
+            let view = view.clone();
+            self.cases.push((name, Rc::new(move || view(value.clone()))));
+        }
+        self
+    }
+
+    /// Render all cases & compare the output against `tests/snapshots/{name}.html`.
+    ///
+    /// If the `YBC_UPDATE_SNAPSHOTS` environment variable is set, the snapshot is written instead;
+    /// a missing snapshot is otherwise a failure, so that it is never silently created on CI.
+    pub async fn assert(self, name: &str) {
+        let mut output = String::new();
+        for (case, view) in self.cases {
+            let html = render(move || view()).await;
+            output.push_str(&format!("<!-- {} -->\n{}\n", case, html));
+        }
+
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("snapshots")
+            .join(format!("{}.html", name));
+        if std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() {
+            std::fs::create_dir_all(path.parent().unwrap()).expect("failed to create snapshots dir");
+            std::fs::write(&path, output).expect("failed to write snapshot");
+            return;
+        }
+        if !path.exists() {
+            panic!(
+                "snapshot `{}` is missing at {}; re-run with `{}=1` to create it",
+                name,
+                path.display(),
+                UPDATE_SNAPSHOTS_VAR,
+            );
+        }
+        let expected = std::fs::read_to_string(&path).expect("failed to read snapshot");
+        if expected != output {
+            let first_diff = expected
+                .lines()
+                .zip(output.lines())
+                .position(|(expected, actual)| expected != actual)
+                .unwrap_or_else(|| expected.lines().count().min(output.lines().count()));
+            panic!(
+                "snapshot `{}` does not match, first difference at line {}; re-run with `{}=1` to update it\n\n--- expected\n{}\n--- actual\n{}",
+                name,
+                first_diff + 1,
+                UPDATE_SNAPSHOTS_VAR,
+                expected,
+                output,
+            );
+        }
+    }
+}
+
+/// Every variant of the `Color` enum.
+pub fn colors() -> Vec<ybc::Color> {
+    use ybc::Color::*;
+    vec![
+        Primary,
+        Link,
+        Info,
+        Success,
+        Warning,
+        Danger,
+        White,
+        Black,
+        Light,
+        Dark,
+        PrimaryLight,
+        LinkLight,
+        InfoLight,
+        SuccessLight,
+        WarningLight,
+        DangerLight,
+        PrimaryDark,
+        LinkDark,
+        InfoDark,
+        SuccessDark,
+        WarningDark,
+        DangerDark,
+    ]
+}
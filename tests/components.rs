@@ -0,0 +1,435 @@
+mod common;
+
+use common::Snapshot;
+use ybc::{Alignment, BreadcrumbSeparator, BreadcrumbSize, Color, NavbarFixed, NavbarItemTag, PaginationItemType, Size, ToastPosition};
+use yew::prelude::*;
+
+#[tokio::test]
+async fn breadcrumb() {
+    Snapshot::default()
+        .case("default", || {
+            html! {
+                <ybc::Breadcrumb>
+                    <li><a href="/">{"Home"}</a></li>
+                    <li class="is-active"><a href="/docs" aria-current="page">{"Docs"}</a></li>
+                </ybc::Breadcrumb>
+            }
+        })
+        .case("classes", || html! {<ybc::Breadcrumb classes={classes!("custom")} />})
+        .variants("size", [BreadcrumbSize::Small, BreadcrumbSize::Medium, BreadcrumbSize::Large], |size| {
+            html! {<ybc::Breadcrumb {size} />}
+        })
+        .variants("alignment", [Alignment::Left, Alignment::Centered, Alignment::Right], |alignment| {
+            html! {<ybc::Breadcrumb {alignment} />}
+        })
+        .variants(
+            "separator",
+            [
+                BreadcrumbSeparator::Arrow,
+                BreadcrumbSeparator::Bullet,
+                BreadcrumbSeparator::Dot,
+                BreadcrumbSeparator::Succeeds,
+            ],
+            |separator| html! {<ybc::Breadcrumb {separator} />},
+        )
+        .case("crumbs", || {
+            let crumbs = vec![
+                ybc::Crumb::link("Home", "/"),
+                ("Docs", Some("/docs")).into(),
+                ybc::Crumb::new("Breadcrumb"),
+            ];
+            html! {<ybc::Breadcrumb {crumbs} />}
+        })
+        .case("collapsed", || {
+            let crumbs = (1..=6)
+                .map(|idx| ybc::Crumb::link(format!("Level {}", idx), format!("/{}", idx)))
+                .collect::<Vec<_>>();
+            html! {<ybc::Breadcrumb {crumbs} max_crumbs={Some(4)} />}
+        })
+        .assert("breadcrumb")
+        .await;
+}
+
+#[tokio::test]
+async fn card() {
+    Snapshot::default()
+        .case("full", || {
+            html! {
+                <ybc::Card>
+                    <ybc::CardHeader><p class="card-header-title">{"Title"}</p></ybc::CardHeader>
+                    <ybc::CardImage><ybc::Image><img src="image.png" /></ybc::Image></ybc::CardImage>
+                    <ybc::CardContent>{"Content"}</ybc::CardContent>
+                    <ybc::CardFooter><a class="card-footer-item">{"Save"}</a></ybc::CardFooter>
+                </ybc::Card>
+            }
+        })
+        .case("card classes", || html! {<ybc::Card classes={classes!("custom")} />})
+        .case("card-header classes", || html! {<ybc::CardHeader classes={classes!("custom")} />})
+        .case("card-image classes", || html! {<ybc::CardImage classes={classes!("custom")} />})
+        .case("card-content classes", || html! {<ybc::CardContent classes={classes!("custom")} />})
+        .case("card-footer classes", || html! {<ybc::CardFooter classes={classes!("custom")} />})
+        .assert("card")
+        .await;
+}
+
+#[tokio::test]
+async fn dropdown() {
+    Snapshot::default()
+        .case("default", || {
+            html! {
+                <ybc::Dropdown button_html={html!{"Menu"}}>
+                    <a class="dropdown-item">{"Item"}</a>
+                </ybc::Dropdown>
+            }
+        })
+        .case("classes", || {
+            html! {<ybc::Dropdown classes={classes!("is-right")} button_classes={classes!("is-primary")} />}
+        })
+        .case("hoverable", || html! {<ybc::Dropdown hoverable=true />})
+        .assert("dropdown")
+        .await;
+}
+
+#[tokio::test]
+async fn menu() {
+    Snapshot::default()
+        .case("full", || {
+            html! {
+                <ybc::Menu>
+                    <ybc::MenuLabel text="General" />
+                    <ybc::MenuList>
+                        <li><a class="is-active">{"Dashboard"}</a></li>
+                    </ybc::MenuList>
+                </ybc::Menu>
+            }
+        })
+        .case("menu classes", || html! {<ybc::Menu classes={classes!("custom")} />})
+        .case("menu-list classes", || html! {<ybc::MenuList classes={classes!("custom")} />})
+        .case("menu-label classes", || html! {<ybc::MenuLabel classes={classes!("custom")} />})
+        .case("tree", || {
+            let sections = vec![
+                ybc::MenuSection::new(
+                    "General",
+                    vec![
+                        ybc::MenuItem {
+                            href: Some("/".into()),
+                            icon: Some(html! {<i class="fas fa-home"></i>}),
+                            ..ybc::MenuItem::new("Dashboard")
+                        },
+                        ybc::MenuItem {
+                            onclick: Some(Callback::noop()),
+                            ..ybc::MenuItem::new("Customers")
+                        },
+                    ],
+                ),
+                ybc::MenuSection::new(
+                    "Administration",
+                    vec![
+                        ybc::MenuItem {
+                            children: vec![ybc::MenuItem { active: true, ..ybc::MenuItem::new("Members") }],
+                            ..ybc::MenuItem::new("Team")
+                        },
+                        ybc::MenuItem {
+                            children: vec![ybc::MenuItem::new("Invoices")],
+                            ..ybc::MenuItem::new("Billing")
+                        },
+                        ybc::MenuItem {
+                            open: true,
+                            children: vec![ybc::MenuItem::new("Cloud")],
+                            ..ybc::MenuItem::new("Storage")
+                        },
+                    ],
+                ),
+            ];
+            html! {<ybc::MenuTree {sections} />}
+        })
+        .assert("menu")
+        .await;
+}
+
+#[tokio::test]
+async fn message() {
+    Snapshot::default()
+        .case("full", || {
+            html! {
+                <ybc::Message>
+                    <ybc::MessageHeader><p>{"Header"}</p></ybc::MessageHeader>
+                    <ybc::MessageBody>{"Body"}</ybc::MessageBody>
+                </ybc::Message>
+            }
+        })
+        .case("message classes", || html! {<ybc::Message classes={classes!("custom")} />})
+        .case("message color", || html! {<ybc::Message color={Color::Danger} />})
+        .case("message-header classes", || html! {<ybc::MessageHeader classes={classes!("custom")} />})
+        .case("message-body classes", || html! {<ybc::MessageBody classes={classes!("custom")} />})
+        .case("dismissible", || {
+            html! {
+                <ybc::Message fade=true>
+                    <ybc::MessageHeader dismissible=true><p>{"Header"}</p></ybc::MessageHeader>
+                    <ybc::MessageBody>{"Body"}</ybc::MessageBody>
+                </ybc::Message>
+            }
+        })
+        .case("message open", || html! {<ybc::Message open={Some(true)} />})
+        .case("message closed", || html! {<ybc::Message open={Some(false)} />})
+        .assert("message")
+        .await;
+}
+
+#[tokio::test]
+async fn modal() {
+    Snapshot::default()
+        .case("modal", || {
+            html! {
+                <ybc::Modal id="modal" trigger={html!{<ybc::Button>{"Open"}</ybc::Button>}}>
+                    <ybc::Box>{"Content"}</ybc::Box>
+                </ybc::Modal>
+            }
+        })
+        .case("modal classes", || html! {<ybc::Modal id="modal" classes={classes!("custom")} />})
+        .case("modal open", || html! {<ybc::Modal id="modal" open={Some(true)} />})
+        .case(
+            "modal closed",
+            || html! {<ybc::Modal id="modal" open={Some(false)} trigger={html!{"Open"}} />},
+        )
+        .case("modal-card", || {
+            html! {
+                <ybc::ModalCard
+                    id="modal-card"
+                    title="Title"
+                    body={html!{"Body"}}
+                    footer={html!{<ybc::Button>{"Save"}</ybc::Button>}}
+                    trigger={html!{<ybc::Button>{"Open"}</ybc::Button>}}
+                />
+            }
+        })
+        .case(
+            "modal-card classes",
+            || html! {<ybc::ModalCard id="modal-card" title="Title" classes={classes!("custom")} />},
+        )
+        .case(
+            "modal-card open",
+            || html! {<ybc::ModalCard id="modal-card" title="Title" open={Some(true)} />},
+        )
+        .case("modal provider", || {
+            html! {
+                <ybc::ModalProvider>
+                    <ybc::Modal id="modal" trigger={html!{"Open"}} />
+                    <ybc::ModalCard id="modal-card" title="Title" />
+                </ybc::ModalProvider>
+            }
+        })
+        .assert("modal")
+        .await;
+}
+
+#[tokio::test]
+async fn navbar() {
+    Snapshot::default()
+        .case("empty", || html! {<ybc::Navbar />})
+        .case("full", || {
+            html! {
+                <ybc::Navbar
+                    navbrand={html!{<ybc::NavbarItem>{"Brand"}</ybc::NavbarItem>}}
+                    navstart={html!{<ybc::NavbarItem>{"Start"}</ybc::NavbarItem>}}
+                    navend={html!{<ybc::NavbarItem>{"End"}</ybc::NavbarItem>}}
+                />
+            }
+        })
+        .case("navburger disabled", || html! {<ybc::Navbar navbrand={html!{"Brand"}} navburger=false />})
+        .case(
+            "navburger_classes",
+            || html! {<ybc::Navbar navbrand={html!{"Brand"}} navburger_classes={classes!("custom")} />},
+        )
+        .case("classes", || html! {<ybc::Navbar classes={classes!("custom")} />})
+        .variants("fixed", [NavbarFixed::Top, NavbarFixed::Bottom], |fixed| html! {<ybc::Navbar {fixed} />})
+        .case("color", || html! {<ybc::Navbar color={Color::Dark} />})
+        .case("transparent", || html! {<ybc::Navbar transparent=true />})
+        .case("spaced", || html! {<ybc::Navbar spaced=true />})
+        .case("padded", || html! {<ybc::Navbar padded=true navstart={html!{"Start"}} />})
+        .assert("navbar")
+        .await;
+}
+
+#[tokio::test]
+async fn navbar_item() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::NavbarItem>{"Item"}</ybc::NavbarItem>})
+        .variants(
+            "tag",
+            [NavbarItemTag::A, NavbarItemTag::Div],
+            |tag| html! {<ybc::NavbarItem {tag}>{"Item"}</ybc::NavbarItem>},
+        )
+        .case("href, rel & target", || {
+            html! {
+                <ybc::NavbarItem
+                    tag={NavbarItemTag::A}
+                    href={Some("https://bulma.io".to_string())}
+                    rel={Some("noopener".to_string())}
+                    target={Some("_blank".to_string())}
+                />
+            }
+        })
+        .case("classes", || html! {<ybc::NavbarItem classes={classes!("custom")} />})
+        .case("has_dropdown", || html! {<ybc::NavbarItem has_dropdown=true />})
+        .case("expanded", || html! {<ybc::NavbarItem expanded=true />})
+        .case("tab", || html! {<ybc::NavbarItem tab=true />})
+        .case("active", || html! {<ybc::NavbarItem tab=true active=true />})
+        .case("divider", || html! {<ybc::NavbarDivider />})
+        .case("divider classes", || html! {<ybc::NavbarDivider classes={classes!("custom")} />})
+        .assert("navbar_item")
+        .await;
+}
+
+#[tokio::test]
+async fn navbar_dropdown() {
+    Snapshot::default()
+        .case("default", || {
+            html! {
+                <ybc::NavbarDropdown navlink={html!{"More"}}>
+                    <ybc::NavbarItem>{"Item"}</ybc::NavbarItem>
+                    <ybc::NavbarDivider />
+                </ybc::NavbarDropdown>
+            }
+        })
+        .case(
+            "classes",
+            || html! {<ybc::NavbarDropdown navlink={html!{}} classes={classes!("custom")} />},
+        )
+        .case("hoverable", || html! {<ybc::NavbarDropdown navlink={html!{}} hoverable=true />})
+        .case("dropup", || html! {<ybc::NavbarDropdown navlink={html!{}} dropup=true />})
+        .case("right", || html! {<ybc::NavbarDropdown navlink={html!{}} right=true />})
+        .case("arrowless", || html! {<ybc::NavbarDropdown navlink={html!{}} arrowless=true />})
+        .case("boxed", || html! {<ybc::NavbarDropdown navlink={html!{}} boxed=true />})
+        .assert("navbar_dropdown")
+        .await;
+}
+
+#[tokio::test]
+async fn pagination() {
+    Snapshot::default()
+        .case("full", || {
+            html! {
+                <ybc::Pagination
+                    previous={html!{<ybc::PaginationItem item_type={PaginationItemType::Previous}>{"Previous"}</ybc::PaginationItem>}}
+                    next={html!{<ybc::PaginationItem item_type={PaginationItemType::Next}>{"Next"}</ybc::PaginationItem>}}
+                >
+                    <li><ybc::PaginationItem item_type={PaginationItemType::Link} label="Goto page 1">{"1"}</ybc::PaginationItem></li>
+                    <li><ybc::PaginationEllipsis /></li>
+                    <li><ybc::PaginationEllipsis character="..." /></li>
+                </ybc::Pagination>
+            }
+        })
+        .case(
+            "classes",
+            || html! {<ybc::Pagination previous={html!{}} next={html!{}} classes={classes!("custom")} />},
+        )
+        .variants("size", [Size::Small, Size::Normal, Size::Medium, Size::Large], |size| {
+            html! {<ybc::Pagination previous={html!{}} next={html!{}} {size} />}
+        })
+        .variants("alignment", [Alignment::Left, Alignment::Centered, Alignment::Right], |alignment| {
+            html! {<ybc::Pagination previous={html!{}} next={html!{}} {alignment} />}
+        })
+        .case("rounded", || html! {<ybc::Pagination previous={html!{}} next={html!{}} rounded=true />})
+        .variants("pages", [1, 5, 10], |current| {
+            html! {<ybc::Pagination {current} total_pages={Some(10)} />}
+        })
+        .case("pages with siblings & boundaries", || {
+            html! {<ybc::Pagination current={10} total_pages={Some(20)} siblings={2} boundaries={2} previous_label="Prev" />}
+        })
+        .case("single page", || html! {<ybc::Pagination total_pages={Some(1)} />})
+        .assert("pagination")
+        .await;
+}
+
+#[tokio::test]
+async fn panel() {
+    Snapshot::default()
+        .case("full", || {
+            html! {
+                <ybc::Panel heading={html!{"Repositories"}}>
+                    <ybc::PanelTabs><a class="is-active">{"All"}</a></ybc::PanelTabs>
+                    <ybc::PanelBlock>{"Block"}</ybc::PanelBlock>
+                </ybc::Panel>
+            }
+        })
+        .case("classes", || html! {<ybc::Panel classes={classes!("is-primary")} />})
+        .case("panel-block tag", || html! {<ybc::PanelBlock tag="a" />})
+        .case("panel-block active", || html! {<ybc::PanelBlock active=true />})
+        .case("virtualize", || {
+            let virtualize = ybc::Virtualize {
+                height: 60.0,
+                overscan: 0,
+                ..ybc::Virtualize::new(
+                    100,
+                    ybc::RowHeight::Measured(30.0),
+                    |idx| html! {<ybc::PanelBlock>{idx}</ybc::PanelBlock>},
+                )
+            };
+            html! {<ybc::Panel heading={html!{"Heading"}} virtualize={Some(virtualize)} />}
+        })
+        .assert("panel")
+        .await;
+}
+
+#[tokio::test]
+async fn tabs() {
+    Snapshot::default()
+        .case("default", || {
+            html! {
+                <ybc::Tabs>
+                    <li class="is-active"><a>{"Pictures"}</a></li>
+                    <li><a>{"Music"}</a></li>
+                </ybc::Tabs>
+            }
+        })
+        .case("classes", || html! {<ybc::Tabs classes={classes!("custom")} />})
+        .variants("alignment", [Alignment::Left, Alignment::Centered, Alignment::Right], |alignment| {
+            html! {<ybc::Tabs {alignment} />}
+        })
+        .variants(
+            "size",
+            [Size::Small, Size::Normal, Size::Medium, Size::Large],
+            |size| html! {<ybc::Tabs {size} />},
+        )
+        .case("boxed", || html! {<ybc::Tabs boxed=true />})
+        .case("toggle", || html! {<ybc::Tabs toggle=true />})
+        .case("rounded", || html! {<ybc::Tabs toggle=true rounded=true />})
+        .case("fullwidth", || html! {<ybc::Tabs fullwidth=true />})
+        .case("container", || html! {<ybc::Tabs container=true><li>{"Tab"}</li></ybc::Tabs>})
+        .assert("tabs")
+        .await;
+}
+
+#[tokio::test]
+async fn toast_provider() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::ToastProvider><p>{"Page"}</p></ybc::ToastProvider>})
+        .variants(
+            "position",
+            [
+                ToastPosition::TopLeft,
+                ToastPosition::TopRight,
+                ToastPosition::BottomLeft,
+                ToastPosition::BottomRight,
+            ],
+            |position| html! {<ybc::ToastProvider {position} />},
+        )
+        .assert("toast_provider")
+        .await;
+}
+
+#[tokio::test]
+async fn virtual_scroll() {
+    Snapshot::default()
+        .case("default", || {
+            let virtualize = ybc::Virtualize::new(1000, ybc::RowHeight::Fixed(100.0), |idx| html! {<p>{idx}</p>});
+            html! {<ybc::VirtualScroll {virtualize} classes={classes!("custom")} />}
+        })
+        .case("short", || {
+            let virtualize = ybc::Virtualize::new(2, ybc::RowHeight::Measured(100.0), |idx| html! {<p>{idx}</p>});
+            html! {<ybc::VirtualScroll {virtualize} />}
+        })
+        .assert("virtual_scroll")
+        .await;
+}
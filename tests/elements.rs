@@ -0,0 +1,337 @@
+mod common;
+
+use common::{colors, Snapshot};
+use ybc::{Alignment, ButtonGroupSize, Color, DataTable, DataTableColumn, HeaderSize, ImageSize, Size, SortDirection, TableSort};
+use yew::prelude::*;
+
+fn sizes() -> Vec<Size> {
+    vec![Size::Small, Size::Normal, Size::Medium, Size::Large]
+}
+
+#[tokio::test]
+async fn block() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Block>{"content"}</ybc::Block>})
+        .case("classes", || html! {<ybc::Block classes={classes!("custom")} />})
+        .assert("block")
+        .await;
+}
+
+#[tokio::test]
+async fn r#box() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Box>{"content"}</ybc::Box>})
+        .case("classes", || html! {<ybc::Box classes={classes!("custom")} />})
+        .assert("box")
+        .await;
+}
+
+#[tokio::test]
+async fn buttons() {
+    Snapshot::default()
+        .case("default", || {
+            html! {
+                <ybc::Buttons>
+                    <ybc::Button>{"one"}</ybc::Button>
+                    <ybc::Button>{"two"}</ybc::Button>
+                </ybc::Buttons>
+            }
+        })
+        .case("classes", || html! {<ybc::Buttons classes={classes!("custom")} />})
+        .variants(
+            "size",
+            [ButtonGroupSize::Small, ButtonGroupSize::Medium, ButtonGroupSize::Large],
+            |size| {
+                html! {<ybc::Buttons {size} />}
+            },
+        )
+        .assert("buttons")
+        .await;
+}
+
+#[tokio::test]
+async fn button() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Button>{"button"}</ybc::Button>})
+        .case("classes", || html! {<ybc::Button classes={classes!("custom")} />})
+        .case("loading", || html! {<ybc::Button loading=true />})
+        .case("static", || html! {<ybc::Button r#static=true />})
+        .case("disabled", || html! {<ybc::Button disabled=true />})
+        .variants("color", colors(), |color| html! {<ybc::Button {color} />})
+        .assert("button")
+        .await;
+}
+
+#[tokio::test]
+async fn button_anchor() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::ButtonAnchor>{"anchor"}</ybc::ButtonAnchor>})
+        .case("href, rel & target", || {
+            html! {<ybc::ButtonAnchor href="https://bulma.io" rel={String::from("noopener")} target={String::from("_blank")} />}
+        })
+        .case("classes", || html! {<ybc::ButtonAnchor classes={classes!("custom")} />})
+        .case("color", || html! {<ybc::ButtonAnchor color={Color::Link} />})
+        .case("loading", || html! {<ybc::ButtonAnchor loading=true />})
+        .case("static", || html! {<ybc::ButtonAnchor r#static=true />})
+        .case("disabled", || html! {<ybc::ButtonAnchor disabled=true />})
+        .assert("button_anchor")
+        .await;
+}
+
+#[tokio::test]
+async fn button_input() {
+    Snapshot::default()
+        .case("submit", || html! {<ybc::ButtonInputSubmit />})
+        .case("submit classes", || html! {<ybc::ButtonInputSubmit classes={classes!("custom")} />})
+        .case("submit loading", || html! {<ybc::ButtonInputSubmit loading=true />})
+        .case("submit static", || html! {<ybc::ButtonInputSubmit r#static=true />})
+        .case("submit disabled", || html! {<ybc::ButtonInputSubmit disabled=true />})
+        .case("reset", || html! {<ybc::ButtonInputReset />})
+        .case("reset classes", || html! {<ybc::ButtonInputReset classes={classes!("custom")} />})
+        .case("reset loading", || html! {<ybc::ButtonInputReset loading=true />})
+        .case("reset static", || html! {<ybc::ButtonInputReset r#static=true />})
+        .case("reset disabled", || html! {<ybc::ButtonInputReset disabled=true />})
+        .assert("button_input")
+        .await;
+}
+
+#[tokio::test]
+async fn content() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Content><p>{"content"}</p></ybc::Content>})
+        .case("tag", || html! {<ybc::Content tag="article" classes={classes!("custom")} />})
+        .assert("content")
+        .await;
+}
+
+#[tokio::test]
+async fn delete() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Delete />})
+        .case("tag", || html! {<ybc::Delete tag="a" classes={classes!("is-small")} />})
+        .assert("delete")
+        .await;
+}
+
+#[tokio::test]
+async fn icon() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Icon><i class="fas fa-home"></i></ybc::Icon>})
+        .case("classes", || html! {<ybc::Icon classes={classes!("custom")} />})
+        .variants("size", sizes(), |size| html! {<ybc::Icon {size} />})
+        .variants("alignment", [Alignment::Left, Alignment::Centered, Alignment::Right], |alignment| {
+            html! {<ybc::Icon {alignment} />}
+        })
+        .assert("icon")
+        .await;
+}
+
+#[tokio::test]
+async fn image() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Image><img src="image.png" /></ybc::Image>})
+        .case("classes", || html! {<ybc::Image classes={classes!("custom")} />})
+        .variants(
+            "size",
+            [
+                ImageSize::Is16x16,
+                ImageSize::Is24x24,
+                ImageSize::Is32x32,
+                ImageSize::Is48x48,
+                ImageSize::Is64x64,
+                ImageSize::Is96x96,
+                ImageSize::Is128x128,
+                ImageSize::IsSquare,
+                ImageSize::Is1by1,
+                ImageSize::Is5by4,
+                ImageSize::Is4by3,
+                ImageSize::Is3by2,
+                ImageSize::Is5by3,
+                ImageSize::Is16by9,
+                ImageSize::Is2by1,
+                ImageSize::Is3by1,
+                ImageSize::Is4by5,
+                ImageSize::Is3by4,
+                ImageSize::Is2by3,
+                ImageSize::Is3by5,
+                ImageSize::Is9by16,
+                ImageSize::Is1by2,
+                ImageSize::Is1by3,
+            ],
+            |size| html! {<ybc::Image {size} />},
+        )
+        .assert("image")
+        .await;
+}
+
+#[tokio::test]
+async fn notification() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Notification>{"content"}</ybc::Notification>})
+        .case("classes", || html! {<ybc::Notification classes={classes!("custom")} />})
+        .variants("color", colors(), |color| html! {<ybc::Notification {color} />})
+        .case(
+            "dismissible",
+            || html! {<ybc::Notification dismissible=true>{"content"}</ybc::Notification>},
+        )
+        .case(
+            "fade",
+            || html! {<ybc::Notification dismissible=true fade=true>{"content"}</ybc::Notification>},
+        )
+        .case("open", || html! {<ybc::Notification open={Some(true)}>{"content"}</ybc::Notification>})
+        .case("closed", || html! {<ybc::Notification open={Some(false)}>{"content"}</ybc::Notification>})
+        .assert("notification")
+        .await;
+}
+
+#[tokio::test]
+async fn progress() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Progress />})
+        .case("max & value", || html! {<ybc::Progress max=100.0 value=42.5 />})
+        .case("classes", || html! {<ybc::Progress classes={classes!("is-small")} />})
+        .case("color", || html! {<ybc::Progress color={Color::Success} />})
+        .assert("progress")
+        .await;
+}
+
+#[tokio::test]
+async fn table() {
+    Snapshot::default()
+        .case("default", || {
+            html! {
+                <ybc::Table>
+                    <thead><tr><th>{"header"}</th></tr></thead>
+                    <tbody><tr><td>{"cell"}</td></tr></tbody>
+                </ybc::Table>
+            }
+        })
+        .case("classes", || html! {<ybc::Table classes={classes!("custom")} />})
+        .case("bordered", || html! {<ybc::Table bordered=true />})
+        .case("striped", || html! {<ybc::Table striped=true />})
+        .case("narrow", || html! {<ybc::Table narrow=true />})
+        .case("hoverable", || html! {<ybc::Table hoverable=true />})
+        .case("fullwidth", || html! {<ybc::Table fullwidth=true />})
+        .case("scrollable", || html! {<ybc::Table scrollable=true />})
+        .case("virtualize", || {
+            let virtualize = ybc::Virtualize {
+                height: 100.0,
+                overscan: 1,
+                ..ybc::Virtualize::new(1000, ybc::RowHeight::Fixed(25.0), |idx| html! {<tr><td>{idx}</td></tr>})
+            };
+            html! {
+                <ybc::Table virtualize={Some(virtualize)}>
+                    <thead><tr><th>{"header"}</th></tr></thead>
+                </ybc::Table>
+            }
+        })
+        .assert("table")
+        .await;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct User {
+    name: &'static str,
+    age: u32,
+}
+
+fn users() -> Vec<User> {
+    vec![
+        User { name: "Carol", age: 35 },
+        User { name: "Alice", age: 42 },
+        User { name: "Bob", age: 27 },
+    ]
+}
+
+fn user_columns() -> Vec<DataTableColumn<User>> {
+    vec![
+        DataTableColumn::new("name", "Name", |user: &User| html! {user.name}).sort_by_key(|user| user.name),
+        DataTableColumn::new("age", "Age", |user: &User| html! {user.age}),
+    ]
+}
+
+#[tokio::test]
+async fn data_table() {
+    let sort = |direction| Some(TableSort { column: "name".into(), direction });
+    Snapshot::default()
+        .case("default", || html! {<DataTable<User> rows={users()} columns={user_columns()} />})
+        .case("empty", || html! {<DataTable<User> rows={vec![]} columns={user_columns()} empty={html!{"No users"}} />})
+        .variants("sort", [SortDirection::Ascending, SortDirection::Descending], move |direction| {
+            html! {<DataTable<User> rows={users()} columns={user_columns()} sort={sort(direction)} />}
+        })
+        .case("server-side sort", move || {
+            html! {<DataTable<User> rows={users()} columns={user_columns()} sort={sort(SortDirection::Ascending)} server_side=true />}
+        })
+        .case("paged", || html! {<DataTable<User> rows={users()} columns={user_columns()} page_size={Some(2)} />})
+        .case("server-side paged", || {
+            html! {<DataTable<User> rows={users()} columns={user_columns()} page_size={Some(3)} page=2 total_rows=7 server_side=true />}
+        })
+        .case("options", || {
+            html! {<DataTable<User> rows={vec![]} columns={user_columns()} classes={classes!("custom")} striped=true hoverable=true scrollable=true />}
+        })
+        .case("selectable", || {
+            let row_key = Callback::from(|user: User| user.name.to_string());
+            html! {<DataTable<User> rows={users()} columns={user_columns()} {row_key} selectable=true selected={Some(vec!["Alice".to_string()])} />}
+        })
+        .case("selectable all", || {
+            html! {<DataTable<User> rows={users()} columns={user_columns()} selectable=true selected={Some(vec!["0".into(), "1".into(), "2".into()])} />}
+        })
+        .case("expandable", || {
+            let detail = Callback::from(|user: User| html! {user.age});
+            html! {<DataTable<User> rows={users()} columns={user_columns()} {detail} />}
+        })
+        .assert("data_table")
+        .await;
+}
+
+#[tokio::test]
+async fn tag() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Tag>{"tag"}</ybc::Tag>})
+        .case("tag", || html! {<ybc::Tag tag="a" classes={classes!("custom")} />})
+        .case("rounded", || html! {<ybc::Tag rounded=true />})
+        .case("delete", || html! {<ybc::Tag delete=true />})
+        .case("color", || html! {<ybc::Tag color={Color::InfoLight} />})
+        .variants("size", sizes(), |size| html! {<ybc::Tag {size} />})
+        .assert("tag")
+        .await;
+}
+
+#[tokio::test]
+async fn tags() {
+    Snapshot::default()
+        .case("default", || {
+            html! {
+                <ybc::Tags>
+                    <ybc::Tag>{"one"}</ybc::Tag>
+                    <ybc::Tag>{"two"}</ybc::Tag>
+                </ybc::Tags>
+            }
+        })
+        .case("classes", || html! {<ybc::Tags classes={classes!("custom")} />})
+        .case("has_addons", || html! {<ybc::Tags has_addons=true />})
+        .assert("tags")
+        .await;
+}
+
+#[tokio::test]
+async fn title() {
+    let header_sizes = [
+        HeaderSize::Is1,
+        HeaderSize::Is2,
+        HeaderSize::Is3,
+        HeaderSize::Is4,
+        HeaderSize::Is5,
+        HeaderSize::Is6,
+    ];
+    Snapshot::default()
+        .case("title", || html! {<ybc::Title>{"title"}</ybc::Title>})
+        .case("title tag", || html! {<ybc::Title tag="h1" classes={classes!("custom")} />})
+        .case("title is_spaced", || html! {<ybc::Title is_spaced=true />})
+        .variants("title size", header_sizes.clone(), |size| html! {<ybc::Title {size} />})
+        .case("subtitle", || html! {<ybc::Subtitle>{"subtitle"}</ybc::Subtitle>})
+        .case("subtitle tag", || html! {<ybc::Subtitle tag="h2" classes={classes!("custom")} />})
+        .variants("subtitle size", header_sizes, |size| html! {<ybc::Subtitle {size} />})
+        .assert("title")
+        .await;
+}
@@ -0,0 +1,441 @@
+mod common;
+
+use common::Snapshot;
+use ybc::{use_form_with, AddonsAlign, Alignment, Color, FormField, GroupedAlign, InputType, Size, ValidationErrors};
+use yew::prelude::*;
+
+fn sizes() -> Vec<Size> {
+    vec![Size::Small, Size::Normal, Size::Medium, Size::Large]
+}
+
+#[tokio::test]
+async fn checkbox() {
+    Snapshot::default()
+        .case(
+            "unchecked",
+            || html! {<ybc::Checkbox name="check" checked=false update={Callback::noop()}>{"label"}</ybc::Checkbox>},
+        )
+        .case(
+            "checked",
+            || html! {<ybc::Checkbox name="check" checked=true update={Callback::noop()} />},
+        )
+        .case(
+            "classes",
+            || html! {<ybc::Checkbox name="check" checked=false update={Callback::noop()} classes={classes!("custom")} />},
+        )
+        .case(
+            "disabled",
+            || html! {<ybc::Checkbox name="check" checked=false update={Callback::noop()} disabled=true />},
+        )
+        .assert("checkbox")
+        .await;
+}
+
+#[tokio::test]
+async fn control() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Control>{"content"}</ybc::Control>})
+        .case("tag", || html! {<ybc::Control tag="p" classes={classes!("has-icons-left")} />})
+        .case("expanded", || html! {<ybc::Control expanded=true />})
+        .assert("control")
+        .await;
+}
+
+#[tokio::test]
+async fn field() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Field><ybc::Control>{"control"}</ybc::Control></ybc::Field>})
+        .case("classes", || html! {<ybc::Field classes={classes!("custom")} />})
+        .case("label", || html! {<ybc::Field label={Some("Label".to_string())} />})
+        .case("label with label_classes", || {
+            html! {<ybc::Field label={Some("Label".to_string())} label_classes={classes!("custom")} />}
+        })
+        .case("label horizontal", || {
+            html! {<ybc::Field label={Some("Label".to_string())} horizontal=true>{"body"}</ybc::Field>}
+        })
+        .case("label horizontal with label_classes", || {
+            html! {<ybc::Field label={Some("Label".to_string())} label_classes={classes!("is-normal")} horizontal=true>{"body"}</ybc::Field>}
+        })
+        .case("help", || html! {<ybc::Field help={Some("Help".to_string())} />})
+        .case("help with help_classes", || {
+            html! {<ybc::Field help={Some("Help".to_string())} help_classes={classes!("custom")} />}
+        })
+        .case("help has error", || {
+            html! {<ybc::Field help={Some("Help".to_string())} help_has_error=true />}
+        })
+        .case("help with help_classes has error", || {
+            html! {<ybc::Field help={Some("Help".to_string())} help_classes={classes!("custom")} help_has_error=true />}
+        })
+        .case("icons_left", || html! {<ybc::Field icons_left=true />})
+        .case("icons_right", || html! {<ybc::Field icons_right=true />})
+        .case("addons", || html! {<ybc::Field addons=true />})
+        .variants("addons_align", [AddonsAlign::Centered, AddonsAlign::Right], |addons_align| {
+            html! {<ybc::Field addons=true {addons_align} />}
+        })
+        .case("grouped", || html! {<ybc::Field grouped=true />})
+        .variants("grouped_align", [GroupedAlign::Centered, GroupedAlign::Right], |grouped_align| {
+            html! {<ybc::Field grouped=true {grouped_align} />}
+        })
+        .case("multiline", || html! {<ybc::Field grouped=true multiline=true />})
+        .case("horizontal", || html! {<ybc::Field horizontal=true>{"body"}</ybc::Field>})
+        .assert("field")
+        .await;
+}
+
+#[tokio::test]
+async fn file() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::File name="file" files={vec![]} update={Callback::noop()} />})
+        .case("selector", || {
+            html! {
+                <ybc::File
+                    name="file"
+                    files={vec![]}
+                    update={Callback::noop()}
+                    selector_label="Upload"
+                    selector_icon={html!{<i class="fas fa-upload"></i>}}
+                />
+            }
+        })
+        .case(
+            "classes",
+            || html! {<ybc::File name="file" files={vec![]} update={Callback::noop()} classes={classes!("custom")} />},
+        )
+        .case("has_name", || {
+            html! {<ybc::File name="file" files={vec![]} update={Callback::noop()} has_name={Some("No file".to_string())} />}
+        })
+        .case(
+            "right",
+            || html! {<ybc::File name="file" files={vec![]} update={Callback::noop()} right=true />},
+        )
+        .case(
+            "fullwidth",
+            || html! {<ybc::File name="file" files={vec![]} update={Callback::noop()} fullwidth=true />},
+        )
+        .case(
+            "boxed",
+            || html! {<ybc::File name="file" files={vec![]} update={Callback::noop()} boxed=true />},
+        )
+        .case(
+            "multiple",
+            || html! {<ybc::File name="file" files={vec![]} update={Callback::noop()} multiple=true />},
+        )
+        .case(
+            "color",
+            || html! {<ybc::File name="file" files={vec![]} update={Callback::noop()} color={Color::Primary} />},
+        )
+        .variants(
+            "size",
+            sizes(),
+            |size| html! {<ybc::File name="file" files={vec![]} update={Callback::noop()} {size} />},
+        )
+        .variants("alignment", [Alignment::Left, Alignment::Centered, Alignment::Right], |alignment| {
+            html! {<ybc::File name="file" files={vec![]} update={Callback::noop()} {alignment} />}
+        })
+        .assert("file")
+        .await;
+}
+
+#[tokio::test]
+async fn input() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Input name="input" value="value" update={Callback::noop()} />})
+        .case(
+            "classes",
+            || html! {<ybc::Input name="input" value="" update={Callback::noop()} classes={classes!("custom")} />},
+        )
+        .variants(
+            "type",
+            [InputType::Text, InputType::Password, InputType::Email, InputType::Tel],
+            |r#type| {
+                html! {<ybc::Input name="input" value="" update={Callback::noop()} {r#type} />}
+            },
+        )
+        .case(
+            "placeholder",
+            || html! {<ybc::Input name="input" value="" update={Callback::noop()} placeholder="Placeholder" />},
+        )
+        .variants(
+            "size",
+            sizes(),
+            |size| html! {<ybc::Input name="input" value="" update={Callback::noop()} {size} />},
+        )
+        .case(
+            "color",
+            || html! {<ybc::Input name="input" value="" update={Callback::noop()} color={Color::Danger} />},
+        )
+        .case(
+            "rounded",
+            || html! {<ybc::Input name="input" value="" update={Callback::noop()} rounded=true />},
+        )
+        .case(
+            "loading",
+            || html! {<ybc::Input name="input" value="" update={Callback::noop()} loading=true />},
+        )
+        .case(
+            "disabled",
+            || html! {<ybc::Input name="input" value="" update={Callback::noop()} disabled=true />},
+        )
+        .case(
+            "readonly",
+            || html! {<ybc::Input name="input" value="" update={Callback::noop()} readonly=true />},
+        )
+        .case(
+            "static",
+            || html! {<ybc::Input name="input" value="" update={Callback::noop()} r#static=true />},
+        )
+        .assert("input")
+        .await;
+}
+
+#[tokio::test]
+async fn radio() {
+    Snapshot::default()
+        .case("unchecked", || {
+            html! {<ybc::Radio name="radio" value="a" checked_value={None::<String>} update={Callback::noop()}>{"A"}</ybc::Radio>}
+        })
+        .case("checked", || {
+            html! {<ybc::Radio name="radio" value="a" checked_value={Some("a".to_string())} update={Callback::noop()} />}
+        })
+        .case("other checked", || {
+            html! {<ybc::Radio name="radio" value="a" checked_value={Some("b".to_string())} update={Callback::noop()} />}
+        })
+        .case("classes", || {
+            html! {<ybc::Radio name="radio" value="a" checked_value={None::<String>} update={Callback::noop()} classes={classes!("custom")} />}
+        })
+        .case("disabled", || {
+            html! {<ybc::Radio name="radio" value="a" checked_value={None::<String>} update={Callback::noop()} disabled=true />}
+        })
+        .assert("radio")
+        .await;
+}
+
+#[tokio::test]
+async fn select() {
+    Snapshot::default()
+        .case("default", || {
+            html! {
+                <ybc::Select name="select" value="a" update={Callback::noop()}>
+                    <option value="a" selected=true>{"A"}</option>
+                    <option value="b">{"B"}</option>
+                </ybc::Select>
+            }
+        })
+        .case(
+            "classes",
+            || html! {<ybc::Select name="select" value="" update={Callback::noop()} classes={classes!("custom")} />},
+        )
+        .variants(
+            "size",
+            sizes(),
+            |size| html! {<ybc::Select name="select" value="" update={Callback::noop()} {size} />},
+        )
+        .case(
+            "color",
+            || html! {<ybc::Select name="select" value="" update={Callback::noop()} color={Color::Warning} />},
+        )
+        .case(
+            "loading",
+            || html! {<ybc::Select name="select" value="" update={Callback::noop()} loading=true />},
+        )
+        .case(
+            "disabled",
+            || html! {<ybc::Select name="select" value="" update={Callback::noop()} disabled=true />},
+        )
+        .assert("select")
+        .await;
+}
+
+#[tokio::test]
+async fn multi_select() {
+    Snapshot::default()
+        .case("default", || {
+            html! {
+                <ybc::MultiSelect name="select" value={vec!["a".to_string(), "b".to_string()]} update={Callback::noop()}>
+                    <option value="a">{"A"}</option>
+                    <option value="b">{"B"}</option>
+                </ybc::MultiSelect>
+            }
+        })
+        .case(
+            "classes",
+            || html! {<ybc::MultiSelect name="select" value={vec![]} update={Callback::noop()} classes={classes!("custom")} />},
+        )
+        .variants(
+            "size",
+            sizes(),
+            |size| html! {<ybc::MultiSelect name="select" value={vec![]} update={Callback::noop()} {size} />},
+        )
+        .case(
+            "color",
+            || html! {<ybc::MultiSelect name="select" value={vec![]} update={Callback::noop()} color={Color::Info} />},
+        )
+        .case(
+            "list_size",
+            || html! {<ybc::MultiSelect name="select" value={vec![]} update={Callback::noop()} list_size=8 />},
+        )
+        .case(
+            "loading",
+            || html! {<ybc::MultiSelect name="select" value={vec![]} update={Callback::noop()} loading=true />},
+        )
+        .case(
+            "disabled",
+            || html! {<ybc::MultiSelect name="select" value={vec![]} update={Callback::noop()} disabled=true />},
+        )
+        .assert("multi_select")
+        .await;
+}
+
+#[tokio::test]
+async fn textarea() {
+    Snapshot::default()
+        .case(
+            "default",
+            || html! {<ybc::TextArea name="text" value="value" update={Callback::noop()} />},
+        )
+        .case(
+            "classes",
+            || html! {<ybc::TextArea name="text" value="" update={Callback::noop()} classes={classes!("custom")} />},
+        )
+        .case(
+            "placeholder",
+            || html! {<ybc::TextArea name="text" value="" update={Callback::noop()} placeholder="Placeholder" />},
+        )
+        .case(
+            "rows",
+            || html! {<ybc::TextArea name="text" value="" update={Callback::noop()} rows=10 />},
+        )
+        .variants(
+            "size",
+            sizes(),
+            |size| html! {<ybc::TextArea name="text" value="" update={Callback::noop()} {size} />},
+        )
+        .case(
+            "color",
+            || html! {<ybc::TextArea name="text" value="" update={Callback::noop()} color={Color::Success} />},
+        )
+        .case(
+            "fixed_size",
+            || html! {<ybc::TextArea name="text" value="" update={Callback::noop()} fixed_size=true />},
+        )
+        .case(
+            "loading",
+            || html! {<ybc::TextArea name="text" value="" update={Callback::noop()} loading=true />},
+        )
+        .case(
+            "disabled",
+            || html! {<ybc::TextArea name="text" value="" update={Callback::noop()} disabled=true />},
+        )
+        .case(
+            "readonly",
+            || html! {<ybc::TextArea name="text" value="" update={Callback::noop()} readonly=true />},
+        )
+        .case(
+            "static",
+            || html! {<ybc::TextArea name="text" value="" update={Callback::noop()} r#static=true />},
+        )
+        .assert("textarea")
+        .await;
+}
+
+#[derive(Clone, Default, PartialEq)]
+struct Profile {
+    name: String,
+    bio: String,
+    role: String,
+    newsletter: bool,
+    plan: Option<String>,
+}
+
+const NAME: FormField<Profile, String> = FormField::new("name", |form| &form.name, |form| &mut form.name);
+const BIO: FormField<Profile, String> = FormField::new("bio", |form| &form.bio, |form| &mut form.bio);
+const ROLE: FormField<Profile, String> = FormField::new("role", |form| &form.role, |form| &mut form.role);
+const NEWSLETTER: FormField<Profile, bool> = FormField::new("newsletter", |form| &form.newsletter, |form| &mut form.newsletter);
+const PLAN: FormField<Profile, Option<String>> = FormField::new("plan", |form| &form.plan, |form| &mut form.plan);
+
+#[derive(Properties, PartialEq)]
+struct ProfileFormProps {
+    /// The initial value of the form; defaults to `Profile::default()`.
+    #[prop_or_default]
+    profile: Option<Profile>,
+}
+
+#[function_component(ProfileForm)]
+fn profile_form(props: &ProfileFormProps) -> Html {
+    let profile = props.profile.clone();
+    let form = use_form_with(move || profile.unwrap_or_default());
+    let (name, bio, role, newsletter, plan) = (
+        form.bind(NAME),
+        form.bind(BIO),
+        form.bind(ROLE),
+        form.bind(NEWSLETTER),
+        form.bind_radio(PLAN),
+    );
+    html! {
+        <form onsubmit={form.onsubmit(Callback::noop())}>
+            <ybc::Input name={name.name} value={name.value} update={name.update} onblur={name.onblur} />
+            <ybc::TextArea name={bio.name} value={bio.value} update={bio.update} onblur={bio.onblur} />
+            <ybc::Select name={role.name} value={role.value} update={role.update} onblur={role.onblur}>
+                <option value="admin">{"Admin"}</option>
+            </ybc::Select>
+            <ybc::Checkbox name={newsletter.name} checked={newsletter.value} update={newsletter.update} onblur={newsletter.onblur} />
+            <ybc::Radio name={plan.name.clone()} value="free" checked_value={plan.value.clone()} update={plan.update.clone()} onblur={plan.onblur.clone()} />
+            <ybc::Radio name={plan.name} value="pro" checked_value={plan.value} update={plan.update} onblur={plan.onblur} />
+            <p>{format!("dirty: {}, touched: {}", form.is_dirty(), form.is_touched(NAME))}</p>
+        </form>
+    }
+}
+
+#[tokio::test]
+async fn form_state() {
+    Snapshot::default()
+        .case("default", || html! {<ProfileForm />})
+        .case("initial value", || {
+            let profile = Profile {
+                name: "Ada".into(),
+                bio: "Mathematician".into(),
+                role: "admin".into(),
+                newsletter: true,
+                plan: Some("pro".into()),
+            };
+            html! {<ProfileForm profile={Some(profile)} />}
+        })
+        .assert("form_state")
+        .await;
+}
+
+fn validation_errors() -> ValidationErrors {
+    let mut errors = ValidationErrors::default();
+    errors.add_field_error("email", "Email is required");
+    errors.add_field_error("email", "Email is invalid");
+    errors.add_field_error("terms", "Terms must be accepted");
+    errors.add_form_error("Signup is closed");
+    errors
+}
+
+fn validated_fields() -> Html {
+    html! {
+        <>
+        <ybc::Field label={Some("Email".to_string())} name={Some("email".to_string())} help={Some("Your email".to_string())}>
+            <ybc::Control><ybc::Input name="email" value="" update={Callback::noop()} /></ybc::Control>
+        </ybc::Field>
+        <ybc::Field name={Some("bio".to_string())} help={Some("About you".to_string())}>
+            <ybc::TextArea name="bio" value="" update={Callback::noop()} />
+        </ybc::Field>
+        <ybc::Checkbox name="terms" checked=false update={Callback::noop()} />
+        </>
+    }
+}
+
+#[tokio::test]
+async fn form_validation() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Form>{validated_fields()}</ybc::Form>})
+        .case(
+            "errors",
+            || html! {<ybc::Form errors={validation_errors()} classes={classes!("custom")}>{validated_fields()}</ybc::Form>},
+        )
+        .case("without form", validated_fields)
+        .assert("form_validation")
+        .await;
+}
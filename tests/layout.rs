@@ -0,0 +1,141 @@
+mod common;
+
+use common::Snapshot;
+use ybc::{Color, HeroSize, SectionSize, TileCtx, TileSize};
+use yew::prelude::*;
+
+#[tokio::test]
+async fn container() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Container>{"content"}</ybc::Container>})
+        .case("classes", || html! {<ybc::Container classes={classes!("custom")} />})
+        .case("fluid", || html! {<ybc::Container fluid=true />})
+        .assert("container")
+        .await;
+}
+
+#[tokio::test]
+async fn footer() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Footer>{"content"}</ybc::Footer>})
+        .case("classes", || html! {<ybc::Footer classes={classes!("custom")} />})
+        .assert("footer")
+        .await;
+}
+
+#[tokio::test]
+async fn hero() {
+    Snapshot::default()
+        .case("body only", || html! {<ybc::Hero body={html!{"body"}} />})
+        .case("head & foot", || {
+            html! {
+                <ybc::Hero
+                    head={html!{"head"}}
+                    head_classes={classes!("head-custom")}
+                    body={html!{"body"}}
+                    body_classes={classes!("body-custom")}
+                    foot={html!{"foot"}}
+                    foot_classes={classes!("foot-custom")}
+                />
+            }
+        })
+        .case("classes", || html! {<ybc::Hero classes={classes!("custom")} body={html!{}} />})
+        .case("fixed_nav", || html! {<ybc::Hero fixed_nav=true body={html!{}} />})
+        .case("bold", || html! {<ybc::Hero bold=true body={html!{}} />})
+        .case("color", || html! {<ybc::Hero color={Color::Primary} body={html!{}} />})
+        .variants(
+            "size",
+            [HeroSize::Medium, HeroSize::Large, HeroSize::Fullheight, HeroSize::FullheightWithNavbar],
+            |size| html! {<ybc::Hero {size} body={html!{}} />},
+        )
+        .assert("hero")
+        .await;
+}
+
+#[tokio::test]
+async fn level() {
+    Snapshot::default()
+        .case("level", || html! {<ybc::Level>{"content"}</ybc::Level>})
+        .case("level tag", || html! {<ybc::Level tag="div" classes={classes!("custom")} />})
+        .case("level-left", || html! {<ybc::LevelLeft>{"content"}</ybc::LevelLeft>})
+        .case("level-left tag", || html! {<ybc::LevelLeft tag="p" classes={classes!("custom")} />})
+        .case("level-right", || html! {<ybc::LevelRight>{"content"}</ybc::LevelRight>})
+        .case("level-right tag", || html! {<ybc::LevelRight tag="p" classes={classes!("custom")} />})
+        .case("level-item", || html! {<ybc::LevelItem>{"content"}</ybc::LevelItem>})
+        .case("level-item tag", || html! {<ybc::LevelItem tag="a" classes={classes!("custom")} />})
+        .assert("level")
+        .await;
+}
+
+#[tokio::test]
+async fn media() {
+    Snapshot::default()
+        .case("media", || html! {<ybc::Media>{"content"}</ybc::Media>})
+        .case("media tag", || html! {<ybc::Media tag="article" classes={classes!("custom")} />})
+        .case("media-left", || html! {<ybc::MediaLeft>{"content"}</ybc::MediaLeft>})
+        .case("media-left tag", || html! {<ybc::MediaLeft tag="figure" classes={classes!("custom")} />})
+        .case("media-right", || html! {<ybc::MediaRight>{"content"}</ybc::MediaRight>})
+        .case(
+            "media-right tag",
+            || html! {<ybc::MediaRight tag="figure" classes={classes!("custom")} />},
+        )
+        .case("media-content", || html! {<ybc::MediaContent>{"content"}</ybc::MediaContent>})
+        .case(
+            "media-content tag",
+            || html! {<ybc::MediaContent tag="section" classes={classes!("custom")} />},
+        )
+        .assert("media")
+        .await;
+}
+
+#[tokio::test]
+async fn section() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Section>{"content"}</ybc::Section>})
+        .case("classes", || html! {<ybc::Section classes={classes!("custom")} />})
+        .variants("size", [SectionSize::Medium, SectionSize::Large], |size| html! {<ybc::Section {size} />})
+        .assert("section")
+        .await;
+}
+
+#[tokio::test]
+async fn tile() {
+    Snapshot::default()
+        .case("default", || html! {<ybc::Tile>{"content"}</ybc::Tile>})
+        .case("tag", || html! {<ybc::Tile tag="article" classes={classes!("custom")} />})
+        .case("vertical", || html! {<ybc::Tile vertical=true />})
+        .variants(
+            "ctx",
+            [TileCtx::Ancestor, TileCtx::Parent, TileCtx::Child],
+            |ctx| html! {<ybc::Tile {ctx} />},
+        )
+        .variants(
+            "size",
+            [
+                TileSize::One,
+                TileSize::Two,
+                TileSize::Three,
+                TileSize::Four,
+                TileSize::Five,
+                TileSize::Six,
+                TileSize::Seven,
+                TileSize::Eight,
+                TileSize::Nine,
+                TileSize::Ten,
+                TileSize::Eleven,
+                TileSize::Twelve,
+            ],
+            |size| html! {<ybc::Tile {size} />},
+        )
+        .case("nested", || {
+            html! {
+                <ybc::Tile ctx={TileCtx::Ancestor}>
+                    <ybc::Tile ctx={TileCtx::Parent} vertical=true size={TileSize::Four}>
+                        <ybc::Tile ctx={TileCtx::Child} classes={classes!("box")}>{"content"}</ybc::Tile>
+                    </ybc::Tile>
+                </ybc::Tile>
+            }
+        })
+        .assert("tile")
+        .await;
+}
@@ -0,0 +1,436 @@
+#![cfg(feature = "router")]
+
+mod common;
+
+use common::{render, Snapshot};
+use serde::Serialize;
+use ybc::{use_route_modal, Color, PaginationItemType, RouteMatch, RouteModal, RouterCrumb, RouterTab, Size};
+use yew::prelude::*;
+use yew_router::history::{AnyHistory, History, MemoryHistory};
+use yew_router::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Routable)]
+enum Route {
+    #[at("/")]
+    Home,
+    #[at("/posts/:id")]
+    Post { id: u32 },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+struct Search {
+    q: String,
+    page: u32,
+}
+
+fn search() -> Option<Search> {
+    Some(Search { q: "rust & yew".into(), page: 2 })
+}
+
+#[derive(Properties, PartialEq)]
+struct RouterProps {
+    children: Children,
+    /// The initial path of the router.
+    #[prop_or_else(|| "/".into())]
+    path: String,
+    /// The initial query parameters of the router.
+    #[prop_or_default]
+    query: Vec<(&'static str, &'static str)>,
+}
+
+/// A router backed by an in-memory history, as the browser history is not available natively.
+#[function_component(TestRouter)]
+fn test_router(props: &RouterProps) -> Html {
+    let history = MemoryHistory::new();
+    history
+        .replace_with_query(props.path.clone(), &props.query)
+        .expect("invalid query");
+    let history = AnyHistory::from(history);
+    html! {
+        <Router {history}>{props.children.clone()}</Router>
+    }
+}
+
+#[tokio::test]
+async fn button_router() {
+    Snapshot::default()
+        .case(
+            "default",
+            || html! {<TestRouter><ybc::ButtonRouter<Route> route={Route::Home}>{"Home"}</ybc::ButtonRouter<Route>></TestRouter>},
+        )
+        .case(
+            "route params",
+            || html! {<TestRouter><ybc::ButtonRouter<Route> route={Route::Post { id: 42 }} /></TestRouter>},
+        )
+        .case(
+            "classes",
+            || html! {<TestRouter><ybc::ButtonRouter<Route> route={Route::Home} classes={classes!("custom")} /></TestRouter>},
+        )
+        .case(
+            "color",
+            || html! {<TestRouter><ybc::ButtonRouter<Route> route={Route::Home} color={Color::Primary} /></TestRouter>},
+        )
+        .case(
+            "loading",
+            || html! {<TestRouter><ybc::ButtonRouter<Route> route={Route::Home} loading=true /></TestRouter>},
+        )
+        .case(
+            "disabled",
+            || html! {<TestRouter><ybc::ButtonRouter<Route> route={Route::Home} disabled=true /></TestRouter>},
+        )
+        .case(
+            "anchor",
+            || html! {<TestRouter><ybc::ButtonAnchorRouter<Route> route={Route::Home}>{"Home"}</ybc::ButtonAnchorRouter<Route>></TestRouter>},
+        )
+        .case(
+            "query",
+            || html! {<TestRouter><ybc::ButtonRouter<Route, Search> route={Route::Home} query={search()} /></TestRouter>},
+        )
+        .case(
+            "anchor query",
+            || html! {<TestRouter><ybc::ButtonAnchorRouter<Route, Search> route={Route::Post { id: 42 }} query={search()} /></TestRouter>},
+        )
+        .assert("button_router")
+        .await;
+}
+
+#[tokio::test]
+async fn pagination_item_router() {
+    Snapshot::default()
+        .variants(
+            "item_type",
+            [PaginationItemType::Link, PaginationItemType::Next, PaginationItemType::Previous],
+            |item_type| {
+                html! {
+                    <TestRouter>
+                        <ybc::PaginationItemRouter<Route> route={Route::Post { id: 2 }} {item_type}>{"2"}</ybc::PaginationItemRouter<Route>>
+                    </TestRouter>
+                }
+            },
+        )
+        .case("query", || {
+            html! {
+                <TestRouter>
+                    <ybc::PaginationItemRouter<Route, Search> route={Route::Home} item_type={PaginationItemType::Link} query={search()}>
+                        {"2"}
+                    </ybc::PaginationItemRouter<Route, Search>>
+                </TestRouter>
+            }
+        })
+        .assert("pagination_item_router")
+        .await;
+}
+
+#[tokio::test]
+async fn router_links_include_query() {
+    let expected = r#"href="/posts/42?q=rust+%26+yew&amp;page=2""#;
+    let button = render(|| {
+        html! {<TestRouter><ybc::ButtonRouter<Route, Search> route={Route::Post { id: 42 }} query={search()} /></TestRouter>}
+    })
+    .await;
+    assert!(button.contains(expected), "{}", button);
+
+    let anchor = render(|| {
+        html! {<TestRouter><ybc::ButtonAnchorRouter<Route, Search> route={Route::Post { id: 42 }} query={search()} /></TestRouter>}
+    })
+    .await;
+    assert!(anchor.contains(expected), "{}", anchor);
+
+    let item = render(|| {
+        html! {
+            <TestRouter>
+                <ybc::PaginationItemRouter<Route, Search> route={Route::Post { id: 42 }} item_type={PaginationItemType::Next} query={search()} />
+            </TestRouter>
+        }
+    })
+    .await;
+    assert!(item.contains(expected), "{}", item);
+
+    let plain = render(|| html! {<TestRouter><ybc::ButtonRouter<Route> route={Route::Post { id: 42 }} /></TestRouter>}).await;
+    assert!(plain.contains(r#"href="/posts/42""#), "{}", plain);
+}
+
+#[tokio::test]
+async fn pagination_router() {
+    Snapshot::default()
+        .case("default", || {
+            html! {<TestRouter><ybc::PaginationRouter<Route> total_pages={3} /></TestRouter>}
+        })
+        .case("query", || {
+            html! {
+                <TestRouter path="/posts/7" query={vec![("q", "rust"), ("page", "5"), ("sort", "name")]}>
+                    <ybc::PaginationRouter<Route> total_pages={10} />
+                </TestRouter>
+            }
+        })
+        .case("param", || {
+            html! {
+                <TestRouter query={vec![("p", "2")]}>
+                    <ybc::PaginationRouter<Route> total_pages={3} param="p" route={Route::Post { id: 1 }} />
+                </TestRouter>
+            }
+        })
+        .case("unmatched route", || {
+            html! {<TestRouter path="/unknown"><ybc::PaginationRouter<Route> total_pages={3} /></TestRouter>}
+        })
+        .assert("pagination_router")
+        .await;
+}
+
+#[tokio::test]
+async fn navbar_item_router() {
+    Snapshot::default()
+        .case("inactive", || {
+            html! {<TestRouter><ybc::NavbarItemRouter<Route> route={Route::Post { id: 42 }}>{"Post"}</ybc::NavbarItemRouter<Route>></TestRouter>}
+        })
+        .case("active", || {
+            html! {
+                <TestRouter path="/posts/42">
+                    <ybc::NavbarItemRouter<Route> route={Route::Post { id: 42 }}>{"Post"}</ybc::NavbarItemRouter<Route>>
+                </TestRouter>
+            }
+        })
+        .case("exact", || {
+            html! {<TestRouter path="/posts/42"><ybc::NavbarItemRouter<Route> route={Route::Home}>{"Home"}</ybc::NavbarItemRouter<Route>></TestRouter>}
+        })
+        .case("prefix", || {
+            html! {
+                <TestRouter path="/posts/42">
+                    <ybc::NavbarItemRouter<Route> route={Route::Home} route_match={RouteMatch::Prefix}>{"Home"}</ybc::NavbarItemRouter<Route>>
+                </TestRouter>
+            }
+        })
+        .case("options", || {
+            html! {
+                <TestRouter>
+                    <ybc::NavbarItemRouter<Route, Search> route={Route::Home} query={search()} classes={classes!("custom")} tab=true expanded=true />
+                </TestRouter>
+            }
+        })
+        .case("navbar", || {
+            html! {
+                <TestRouter>
+                    <ybc::Navbar navstart={html! {<ybc::NavbarItemRouter<Route> route={Route::Home}>{"Home"}</ybc::NavbarItemRouter<Route>>}} />
+                </TestRouter>
+            }
+        })
+        .assert("navbar_item_router")
+        .await;
+}
+
+fn tabs() -> Vec<RouterTab<Route>> {
+    vec![
+        RouterTab::new("Home", Route::Home),
+        ("Post", Route::Post { id: 42 }, Some(html! {<i class="fas fa-image"></i>})).into(),
+    ]
+}
+
+#[tokio::test]
+async fn tabs_router() {
+    Snapshot::default()
+        .case("default", || html! {<TestRouter><ybc::TabsRouter<Route> tabs={tabs()} /></TestRouter>})
+        .case("active", || {
+            html! {<TestRouter path="/posts/42"><ybc::TabsRouter<Route> tabs={tabs()} /></TestRouter>}
+        })
+        .case("unmatched", || {
+            html! {<TestRouter path="/posts/7"><ybc::TabsRouter<Route> tabs={tabs()} /></TestRouter>}
+        })
+        .case("prefix", || {
+            html! {
+                <TestRouter path="/posts/42">
+                    <ybc::TabsRouter<Route> tabs={tabs()} route_match={RouteMatch::Prefix} boxed=true fullwidth=true />
+                </TestRouter>
+            }
+        })
+        .case("hero foot", || {
+            html! {
+                <TestRouter>
+                    <ybc::Hero body={html! {}} foot={html! {<ybc::TabsRouter<Route> tabs={tabs()} boxed=true container=true />}} />
+                </TestRouter>
+            }
+        })
+        .assert("tabs_router")
+        .await;
+}
+
+fn parent(route: Route) -> Option<Route> {
+    match route {
+        Route::Home => None,
+        Route::Post { .. } => Some(Route::Home),
+    }
+}
+
+fn label(route: Route) -> String {
+    match route {
+        Route::Home => "Home".into(),
+        Route::Post { id } => format!("Post {}", id),
+    }
+}
+
+#[tokio::test]
+async fn breadcrumb_router() {
+    Snapshot::default()
+        .case("hierarchy", || {
+            html! {
+                <TestRouter path="/posts/42">
+                    <ybc::BreadcrumbRouter<Route> parent={Some(Callback::from(parent))} label={Some(Callback::from(label))} />
+                </TestRouter>
+            }
+        })
+        .case("root", || {
+            html! {
+                <TestRouter>
+                    <ybc::BreadcrumbRouter<Route> parent={Some(Callback::from(parent))} label={Some(Callback::from(label))} />
+                </TestRouter>
+            }
+        })
+        .case("default label", || {
+            html! {<TestRouter path="/posts/42"><ybc::BreadcrumbRouter<Route> parent={Some(Callback::from(parent))} /></TestRouter>}
+        })
+        .case("crumbs", || {
+            let crumbs = vec![
+                RouterCrumb::new("Home", Some(Route::Home)),
+                ("Posts", None).into(),
+                ("Post 1", Some(Route::Post { id: 1 })).into(),
+                ("Post 2", Some(Route::Post { id: 2 })).into(),
+                ("Edit", None).into(),
+            ];
+            html! {<TestRouter><ybc::BreadcrumbRouter<Route> {crumbs} max_crumbs={Some(3)} /></TestRouter>}
+        })
+        .assert("breadcrumb_router")
+        .await;
+}
+
+fn menu_sections() -> Vec<ybc::MenuSection<Route>> {
+    vec![
+        ybc::MenuSection::new(
+            "General",
+            vec![ybc::MenuItem {
+                route: Some(Route::Home),
+                ..ybc::MenuItem::new("Home")
+            }],
+        ),
+        ybc::MenuSection::new(
+            "Posts",
+            vec![ybc::MenuItem {
+                children: vec![
+                    ybc::MenuItem {
+                        route: Some(Route::Post { id: 1 }),
+                        ..ybc::MenuItem::new("Post 1")
+                    },
+                    ybc::MenuItem {
+                        route: Some(Route::Post { id: 2 }),
+                        ..ybc::MenuItem::new("Post 2")
+                    },
+                ],
+                ..ybc::MenuItem::new("Recent")
+            }],
+        ),
+    ]
+}
+
+#[tokio::test]
+async fn menu_router() {
+    Snapshot::default()
+        .case(
+            "home",
+            || html! {<TestRouter><ybc::MenuRouter<Route> sections={menu_sections()} /></TestRouter>},
+        )
+        .case("nested", || {
+            html! {<TestRouter path="/posts/2"><ybc::MenuRouter<Route> sections={menu_sections()} /></TestRouter>}
+        })
+        .case("prefix", || {
+            html! {
+                <TestRouter path="/posts/3">
+                    <ybc::MenuRouter<Route> sections={menu_sections()} route_match={RouteMatch::Prefix} />
+                </TestRouter>
+            }
+        })
+        .assert("menu_router")
+        .await;
+}
+
+#[tokio::test]
+async fn component_routers() {
+    Snapshot::default()
+        .case("panel block", || {
+            html! {<TestRouter><ybc::PanelBlockRouter<Route> route={Route::Post { id: 1 }}>{"Post"}</ybc::PanelBlockRouter<Route>></TestRouter>}
+        })
+        .case("panel block active", || {
+            html! {
+                <TestRouter>
+                    <ybc::PanelBlockRouter<Route, Search> route={Route::Home} query={search()} active=true>{"Home"}</ybc::PanelBlockRouter<Route, Search>>
+                </TestRouter>
+            }
+        })
+        .case("tag", || html! {<TestRouter><ybc::TagRouter<Route> route={Route::Home}>{"Home"}</ybc::TagRouter<Route>></TestRouter>})
+        .case("tag options", || {
+            html! {
+                <TestRouter>
+                    <ybc::TagRouter<Route> route={Route::Post { id: 2 }} classes={classes!("custom")} color={Color::Info} rounded=true size={Size::Large}>
+                        {"Post"}
+                    </ybc::TagRouter<Route>>
+                </TestRouter>
+            }
+        })
+        .case("tag delete", || html! {<TestRouter><ybc::TagRouter<Route> route={Route::Home} delete=true /></TestRouter>})
+        .case("card", || {
+            html! {
+                <TestRouter>
+                    <ybc::CardRouter<Route> route={Route::Post { id: 3 }} classes={classes!("custom")}>
+                        <ybc::CardContent>{"Post"}</ybc::CardContent>
+                    </ybc::CardRouter<Route>>
+                </TestRouter>
+            }
+        })
+        .case("dropdown item", || {
+            html! {<TestRouter><ybc::DropdownItemRouter<Route> route={Route::Home}>{"Home"}</ybc::DropdownItemRouter<Route>></TestRouter>}
+        })
+        .case("dropdown item options", || {
+            html! {
+                <TestRouter>
+                    <ybc::DropdownItemRouter<Route, Search> route={Route::Home} query={search()} classes={classes!("custom")} active=true>
+                        {"Search"}
+                    </ybc::DropdownItemRouter<Route, Search>>
+                </TestRouter>
+            }
+        })
+        .assert("component_routers")
+        .await;
+}
+
+#[derive(Properties, PartialEq)]
+struct RouteModalProps {
+    /// Bind the modal to the `edit` query parameter instead of the post routes.
+    #[prop_or_default]
+    query: bool,
+}
+
+#[function_component(PostModal)]
+fn post_modal(props: &RouteModalProps) -> Html {
+    let modal = match props.query {
+        true => RouteModal::query("edit"),
+        false => RouteModal::route(|route: &Route| matches!(route, Route::Post { .. }).then_some(Route::Home)),
+    };
+    let modal = use_route_modal(modal);
+    html! {
+        <ybc::ModalCard id="post" title="Post" open={modal.is_open()} on_close={modal.on_close()} body={html! {modal.value().unwrap_or_default()}} />
+    }
+}
+
+#[tokio::test]
+async fn route_modal() {
+    Snapshot::default()
+        .case("route closed", || html! {<TestRouter><PostModal /></TestRouter>})
+        .case("route open", || html! {<TestRouter path="/posts/42"><PostModal /></TestRouter>})
+        .case(
+            "query closed",
+            || html! {<TestRouter query={vec![("q", "rust")]}><PostModal query=true /></TestRouter>},
+        )
+        .case(
+            "query open",
+            || html! {<TestRouter query={vec![("q", "rust"), ("edit", "42")]}><PostModal query=true /></TestRouter>},
+        )
+        .assert("route_modal")
+        .await;
+}
@@ -0,0 +1,118 @@
+//! Server-side rendering of the components which depend on browser-only APIs.
+
+mod common;
+
+use common::render;
+use ybc::use_modal;
+use yew::prelude::*;
+
+#[tokio::test]
+async fn modal_renders_without_agent() {
+    let html = render(|| {
+        html! {
+            <ybc::Modal id="modal-0" trigger={html!{<ybc::Button>{"Open"}</ybc::Button>}}>
+                <p>{"Content"}</p>
+            </ybc::Modal>
+        }
+    })
+    .await;
+    assert!(
+        html.contains(r#"<div id="modal-0" role="dialog" aria-modal="true" tabindex="-1" class="modal">"#),
+        "{}",
+        html
+    );
+    assert!(html.contains(r#"<div class="modal-content"><p>Content</p></div>"#), "{}", html);
+}
+
+#[tokio::test]
+async fn modal_card_renders_without_agent() {
+    let html = render(|| {
+        html! {
+            <ybc::ModalCard id="modal-1" title="Title" body={html!{"Body"}} footer={html!{"Footer"}} />
+        }
+    })
+    .await;
+    assert!(
+        html.contains(r#"<div id="modal-1" role="dialog" aria-modal="true" aria-labelledby="modal-1-title" tabindex="-1" class="modal">"#),
+        "{}",
+        html
+    );
+    assert!(html.contains(r#"<p id="modal-1-title" class="modal-card-title">Title</p>"#), "{}", html);
+    assert!(html.contains(r#"<section class="modal-card-body">Body</section>"#), "{}", html);
+}
+
+#[tokio::test]
+async fn use_modal_renders_within_provider() {
+    #[function_component(Status)]
+    fn status() -> Html {
+        let modal = use_modal("modal-3");
+        html! { <p>{modal.id()}{": "}{modal.is_open()}</p> }
+    }
+
+    let html = render(|| {
+        html! {
+            <ybc::ModalProvider>
+                <Status />
+                <ybc::Modal id="modal-3" />
+            </ybc::ModalProvider>
+        }
+    })
+    .await;
+    assert!(html.starts_with("<p>modal-3: false</p>"), "{}", html);
+    assert!(
+        html.contains(r#"<div id="modal-3" role="dialog" aria-modal="true" tabindex="-1" class="modal">"#),
+        "{}",
+        html
+    );
+}
+
+#[tokio::test]
+async fn file_renders_placeholder_name() {
+    let html = render(|| {
+        html! {
+            <ybc::File name="upload" files={vec![]} update={Callback::noop()} has_name={Some("No file".to_string())} />
+        }
+    })
+    .await;
+    assert!(html.starts_with(r#"<div class="file has-name">"#), "{}", html);
+    assert!(html.contains(r#"<span class="file-name">No file</span>"#), "{}", html);
+}
+
+#[test]
+fn server_renderer_renders_across_threads() {
+    #[function_component(App)]
+    fn app() -> Html {
+        html! {
+            <ybc::Modal id="modal-2">{"Content"}</ybc::Modal>
+        }
+    }
+
+    // The renderer is created on this thread & moved to another one, which requires it to be `Send`.
+    let renderer = yew::ServerRenderer::<App>::new().hydratable(false);
+    let html = std::thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("failed to build runtime");
+        runtime.block_on(renderer.render())
+    })
+    .join()
+    .expect("rendering thread panicked");
+    assert!(
+        html.contains(r#"<div id="modal-2" role="dialog" aria-modal="true" tabindex="-1" class="modal">"#),
+        "{}",
+        html
+    );
+}
+
+#[tokio::test]
+async fn dialog_provider_renders_children() {
+    #[function_component(Confirm)]
+    fn confirm() -> Html {
+        let dialog = ybc::use_dialog();
+        let onclick = Callback::from(move |_| dialog.confirm_with(ybc::DialogOptions::new("Delete?"), Callback::noop()));
+        html! { <ybc::Button {onclick}>{"Delete"}</ybc::Button> }
+    }
+
+    let html = render(|| html! { <ybc::DialogProvider><Confirm /></ybc::DialogProvider> }).await;
+    assert_eq!(html, r#"<button class="button">Delete</button>"#);
+}